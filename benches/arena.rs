@@ -0,0 +1,49 @@
+//! compares building and searching a wide tree with [Node] and [Tree], run with `cargo bench`
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use a_ntree::arena::Tree;
+use a_ntree::Node;
+
+const NODES: usize = 100_000;
+/// the plain uniqueness check is quadratic, so it only gets a smaller tree
+const UNINDEXED_NODES: usize = 10_000;
+
+fn time<R>(name: &str, f: impl FnOnce() -> R) -> R {
+    let start = Instant::now();
+    let result = black_box(f());
+    let elapsed: Duration = start.elapsed();
+    println!("{name:<40} {elapsed:>12.2?}");
+    result
+}
+
+fn main() {
+    let node = time(&format!("Node::add_leaf ({UNINDEXED_NODES} nodes)"), || {
+        let root = Node::new(0);
+        for value in 1..UNINDEXED_NODES {
+            root.add_leaf(value);
+        }
+        root
+    });
+    time("Node::find", || node.find(&(UNINDEXED_NODES - 1)));
+
+    let indexed = time(&format!("Node::add_leaf_indexed ({NODES} nodes)"), || {
+        let root = Node::new(0);
+        for value in 1..NODES {
+            root.add_leaf_indexed(value);
+        }
+        root
+    });
+    time("Node::find", || indexed.find(&(NODES - 1)));
+
+    let mut tree = time(&format!("Tree::add_child ({NODES} nodes)"), || {
+        let mut tree = Tree::new(0);
+        for value in 1..NODES {
+            tree.add_child(tree.root(), value);
+        }
+        tree
+    });
+    time("Tree::find", || tree.find(&(NODES - 1)));
+    time("Tree::walk", || tree.walk(tree.root(), a_ntree::TraversalOrder::PreOrder).count());
+    let last = tree.find(&(NODES - 1)).unwrap();
+    time("Tree::remove", || tree.remove(last));
+}
@@ -0,0 +1,290 @@
+//! a tree that keeps all of its nodes in one [Vec]
+//!
+//! nodes are addressed by [`NodeId`] instead of handles, links between them are indices.
+//! Values are unique like in [`Node`](crate::Node), but the check is a hash lookup,
+//! so building large trees takes linear instead of quadratic time
+use std::collections::{HashMap, VecDeque};
+use std::hash::{BuildHasher, Hash, RandomState};
+use std::vec;
+use std::vec::Vec;
+use crate::TraversalOrder;
+
+/// the address of a node in a [Tree]
+///
+/// an id stays valid until its node is removed, afterwards it no longer resolves,
+/// even when the slot is reused by a new node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId {
+    index: usize,
+    generation: u32,
+}
+
+#[derive(Debug)]
+struct Slot<T> {
+    generation: u32,
+    entry: Option<Entry<T>>,
+}
+
+#[derive(Debug)]
+struct Entry<T> {
+    value: T,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+/// a tree with unique values whose nodes are stored in a contiguous [Vec]
+#[derive(Debug)]
+pub struct Tree<T> where T: Hash + Eq {
+    slots: Vec<Slot<T>>,
+    /// indices of empty slots, reused before the [Vec] grows
+    free: Vec<usize>,
+    /// value hash -> nodes with a value of that hash
+    index: HashMap<u64, Vec<NodeId>>,
+    hasher: RandomState,
+    len: usize,
+}
+
+impl<T> Tree<T> where T: Hash + Eq {
+    /// creates a new [Tree] containing only the root
+    /// ## Example
+    /// ```
+    /// use a_ntree::arena::Tree;
+    /// let tree = Tree::new(10);
+    ///
+    /// assert_eq!(tree.get(tree.root()), Some(&10));
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn new(root: T) -> Self {
+        let mut tree = Self {
+            slots: vec![],
+            free: vec![],
+            index: HashMap::new(),
+            hasher: RandomState::new(),
+            len: 0,
+        };
+        tree.insert(root, None);
+        tree
+    }
+
+    /// returns the id of the root, the root can't be removed
+    #[must_use]
+    pub const fn root(&self) -> NodeId {
+        NodeId { index: 0, generation: 0 }
+    }
+
+    /// returns the number of nodes in the [Tree]
+    ///
+    /// a tree always contains its root, so there is no `is_empty`
+    #[allow(clippy::len_without_is_empty)]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    fn entry(&self, id: NodeId) -> Option<&Entry<T>> {
+        self.slots.get(id.index)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.entry.as_ref())
+    }
+
+    fn entry_mut(&mut self, id: NodeId) -> Option<&mut Entry<T>> {
+        self.slots.get_mut(id.index)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.entry.as_mut())
+    }
+
+    /// returns the value of a node, or [None] if the id doesn't resolve
+    #[must_use]
+    pub fn get(&self, id: NodeId) -> Option<&T> {
+        self.entry(id).map(|entry| &entry.value)
+    }
+
+    /// returns the parent of a node, the root has no parent
+    #[must_use]
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.entry(id)?.parent
+    }
+
+    /// returns the children of a node in order, or an empty slice if the id doesn't resolve
+    /// ## Example
+    /// ```
+    /// use a_ntree::arena::Tree;
+    /// let mut tree = Tree::new(10);
+    /// let child = tree.add_child(tree.root(), 20).unwrap();
+    ///
+    /// assert_eq!(tree.children(tree.root()), &[child]);
+    /// assert_eq!(tree.parent(child), Some(tree.root()));
+    /// ```
+    #[must_use]
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        self.entry(id).map_or(&[], |entry| &entry.children)
+    }
+
+    /// adds a value as the last child of `parent`
+    ///
+    /// returns the id of the new node, or [None] if the value already is in the tree
+    /// or `parent` doesn't resolve
+    /// ## Example
+    /// ```
+    /// use a_ntree::arena::Tree;
+    /// let mut tree = Tree::new(10);
+    /// let child = tree.add_child(tree.root(), 20).unwrap();
+    ///
+    /// assert!(tree.add_child(child, 30).is_some());
+    /// assert!(tree.add_child(child, 10).is_none());
+    /// assert_eq!(tree.len(), 3);
+    /// ```
+    pub fn add_child(&mut self, parent: NodeId, value: T) -> Option<NodeId> {
+        if self.entry(parent).is_none() || self.find(&value).is_some() {
+            return None;
+        }
+        let id = self.insert(value, Some(parent));
+        self.entry_mut(parent)?.children.push(id);
+        Some(id)
+    }
+
+    /// stores a value in a free slot without linking it to its parent
+    fn insert(&mut self, value: T, parent: Option<NodeId>) -> NodeId {
+        let hash = self.hasher.hash_one(&value);
+        let entry = Some(Entry { value, parent, children: vec![] });
+        let id = if let Some(index) = self.free.pop() {
+            let slot = &mut self.slots[index];
+            slot.entry = entry;
+            NodeId { index, generation: slot.generation }
+        } else {
+            self.slots.push(Slot { generation: 0, entry });
+            NodeId { index: self.slots.len() - 1, generation: 0 }
+        };
+        self.index.entry(hash).or_default().push(id);
+        self.len += 1;
+        id
+    }
+
+    /// searches the node with a value in average constant time
+    /// ## Example
+    /// ```
+    /// use a_ntree::arena::Tree;
+    /// let mut tree = Tree::new("root");
+    /// let child = tree.add_child(tree.root(), "child").unwrap();
+    ///
+    /// assert_eq!(tree.find(&"child"), Some(child));
+    /// assert_eq!(tree.find(&"other"), None);
+    /// ```
+    #[must_use]
+    pub fn find(&self, value: &T) -> Option<NodeId> {
+        let candidates = self.index.get(&self.hasher.hash_one(value))?;
+        candidates.iter().copied().find(|id| self.get(*id) == Some(value))
+    }
+
+    /// removes a node together with its subtree and returns its value
+    ///
+    /// returns [None] for the root or an id that doesn't resolve
+    /// ## Example
+    /// ```
+    /// use a_ntree::arena::Tree;
+    /// let mut tree = Tree::new(10);
+    /// let child = tree.add_child(tree.root(), 20).unwrap();
+    /// tree.add_child(child, 30);
+    ///
+    /// assert_eq!(tree.remove(child), Some(20));
+    /// assert_eq!(tree.len(), 1);
+    /// assert!(tree.find(&30).is_none());
+    /// assert!(tree.get(child).is_none());
+    /// ```
+    pub fn remove(&mut self, id: NodeId) -> Option<T> {
+        let parent = self.parent(id)?;
+        self.entry_mut(parent)?.children.retain(|child| *child != id);
+
+        let mut removed = None;
+        let mut stack = vec![id];
+        while let Some(current) = stack.pop() {
+            let slot = &mut self.slots[current.index];
+            let Some(entry) = slot.entry.take() else { continue };
+            slot.generation = slot.generation.wrapping_add(1);
+            self.free.push(current.index);
+            self.len -= 1;
+
+            let hash = self.hasher.hash_one(&entry.value);
+            if let Some(candidates) = self.index.get_mut(&hash) {
+                candidates.retain(|candidate| *candidate != current);
+                if candidates.is_empty() {
+                    self.index.remove(&hash);
+                }
+            }
+            stack.extend(entry.children);
+            if current == id {
+                removed = Some(entry.value);
+            }
+        }
+        removed
+    }
+
+    /// returns a lazy iterator over the ids of the subtree of `start` in the given [`TraversalOrder`]
+    ///
+    /// an id that doesn't resolve yields nothing
+    /// ## Example
+    /// ```
+    /// use a_ntree::arena::Tree;
+    /// use a_ntree::TraversalOrder;
+    /// let mut tree = Tree::new(1);
+    /// let two = tree.add_child(tree.root(), 2).unwrap();
+    /// tree.add_child(two, 3);
+    /// tree.add_child(tree.root(), 4);
+    ///
+    /// let values = |order| tree.walk(tree.root(), order).map(|id| tree.get(id).copied().unwrap()).collect::<Vec<_>>();
+    /// assert_eq!(values(TraversalOrder::PreOrder), vec![1, 2, 3, 4]);
+    /// assert_eq!(values(TraversalOrder::PostOrder), vec![3, 2, 4, 1]);
+    /// assert_eq!(values(TraversalOrder::BreadthFirst), vec![1, 2, 4, 3]);
+    /// ```
+    #[must_use]
+    pub fn walk(&self, start: NodeId, order: TraversalOrder) -> Walk<'_, T> {
+        let start = self.entry(start).map(|_| start);
+        let pending = match order {
+            TraversalOrder::PreOrder => Pending::PreOrder(start.into_iter().collect()),
+            TraversalOrder::PostOrder => Pending::PostOrder(start.into_iter().map(|id| (id, 0)).collect()),
+            TraversalOrder::BreadthFirst => Pending::BreadthFirst(start.into_iter().collect()),
+        };
+        Walk { tree: self, pending }
+    }
+}
+
+/// a lazy iterator over the ids of a subtree, created by [`Tree::walk()`]
+pub struct Walk<'a, T> where T: Hash + Eq {
+    tree: &'a Tree<T>,
+    pending: Pending,
+}
+
+enum Pending {
+    PreOrder(Vec<NodeId>),
+    /// every node is paired with the index of its next child to descend into
+    PostOrder(Vec<(NodeId, usize)>),
+    BreadthFirst(VecDeque<NodeId>),
+}
+
+impl<T> Iterator for Walk<'_, T> where T: Hash + Eq {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.pending {
+            Pending::PreOrder(stack) => {
+                let id = stack.pop()?;
+                stack.extend(self.tree.children(id).iter().rev());
+                Some(id)
+            }
+            Pending::PostOrder(stack) => loop {
+                let (id, next_child) = stack.last_mut()?;
+                if let Some(child) = self.tree.children(*id).get(*next_child) {
+                    *next_child += 1;
+                    stack.push((*child, 0));
+                } else {
+                    return stack.pop().map(|(id, _)| id);
+                }
+            },
+            Pending::BreadthFirst(queue) => {
+                let id = queue.pop_front()?;
+                queue.extend(self.tree.children(id));
+                Some(id)
+            }
+        }
+    }
+}
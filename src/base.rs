@@ -1,92 +1,685 @@
-use std::rc::{Rc, Weak};
-use std::cell::RefCell;
-use std::fmt::Debug;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::rc::{Rc, Weak};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::{Cell, Ref, RefCell, RefMut};
+use core::fmt::Debug;
+use crate::error::{AddError, InvariantError};
+use crate::iter::WalkControl;
+use crate::policy::TreePolicy;
+#[cfg(feature = "std")]
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use std::hash::DefaultHasher;
 
 #[derive(Debug)]
-pub struct RawNode<T> where T: PartialEq {
-    value: T,
-    children: RefCell<Vec<Rc<RawNode<T>>>>,
-    pub parent: RefCell<Weak<RawNode<T>>>,
+pub struct RawNode<T> {
+    value: RefCell<T>,
+    children: RefCell<Vec<Rc<Self>>>,
+    pub parent: RefCell<Weak<Self>>,
+    policy: Cell<TreePolicy>,
+    /// only kept on the root, [None] until the first indexed insert and after a value changes or the children are replaced
+    index: RefCell<Option<ValueIndex<T>>>,
+}
+
+/// value hash -> number of values with that hash in the tree, with the function that hashed them
+#[derive(Debug)]
+struct ValueIndex<T> {
+    counts: BTreeMap<u64, usize>,
+    hash: fn(&T) -> u64,
+}
+
+impl<T> ValueIndex<T> {
+    /// adds the values of the subtree of `node` to the counts, or takes them away if it was removed
+    fn count(&mut self, node: &Rc<RawNode<T>>, linked: bool) {
+        node.visit(&mut |node| {
+            let hash = (self.hash)(&node.value());
+            if linked {
+                *self.counts.entry(hash).or_insert(0) += 1;
+            } else if let Some(count) = self.counts.get_mut(&hash) {
+                *count -= 1;
+                if *count == 0 {
+                    self.counts.remove(&hash);
+                }
+            }
+        });
+    }
 }
 
 #[allow(unused)]
-impl<T> RawNode<T> where T: PartialEq {
-    pub fn new(value: T) -> Self {
-        Self { value, parent: RefCell::new(Weak::new()), children: RefCell::new(vec![]) }
+impl<T> RawNode<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            value: RefCell::new(value),
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(Vec::new()),
+            policy: Cell::new(TreePolicy::UniqueValues),
+            index: RefCell::new(None),
+        }
     }
 
-    pub const fn value(&self) -> &T {
-        &self.value
+    pub fn value(&self) -> Ref<'_, T> {
+        self.value.borrow()
+    }
+
+    pub fn value_mut(&self) -> RefMut<'_, T> {
+        self.invalidate_index();
+        self.value.borrow_mut()
     }
 
     pub fn parent(&self) -> Option<Rc<Self>> {
         self.parent.borrow().upgrade()
     }
 
+    /// the parent of this node and the index of this node among its children
+    pub fn position(self: &Rc<Self>) -> Option<(Rc<Self>, usize)> {
+        let parent = self.parent()?;
+        let idx = parent.children.borrow().iter().position(|node| Rc::ptr_eq(node, self))?;
+        Some((parent, idx))
+    }
+
     pub const fn children(&self) -> &RefCell<Vec<Rc<Self>>> {
         &self.children
     }
 
-    pub fn add_child(self: &Rc<Self>, child: &Rc<Self>) -> bool {
-        return if self.get_root().unique_nodes(child) {
-            self.children.borrow_mut().push(Rc::clone(child));
+    pub fn allows_duplicates(&self) -> bool {
+        self.policy() == TreePolicy::AllowDuplicates
+    }
+
+    pub const fn policy(&self) -> TreePolicy {
+        self.policy.get()
+    }
+
+    pub fn set_policy(&self, policy: TreePolicy) {
+        self.policy.set(policy);
+    }
+
+    pub fn add_child_unchecked(self: &Rc<Self>, child: &Rc<Self>) -> bool {
+        if child.contains_node(self) {
+            return false;
+        }
+        child.detach();
+        let idx = self.children.borrow().len();
+        self.link_child(idx, child);
+        true
+    }
+
+    /// inserts `child` at `idx` without any checks
+    pub fn link_child(self: &Rc<Self>, idx: usize, child: &Rc<Self>) {
+        self.attach(idx, child);
+        self.update_index(|index| index.count(child, true));
+    }
+
+    /// inserts `child` at `idx` without any checks, leaving the index of the tree as it is
+    fn attach(self: &Rc<Self>, idx: usize, child: &Rc<Self>) {
+        self.children.borrow_mut().insert(idx, Rc::clone(child));
+        *child.parent.borrow_mut() = Rc::downgrade(self);
+        // the index of the child's old tree is no longer needed
+        *child.index.borrow_mut() = None;
+        debug_assert!(self.holds_at(idx, child), "a linked child must point back at its parent");
+    }
+
+    /// true if `child` sits at `idx` and points back at this node, the full scan is left to `validate_links`
+    fn holds_at(self: &Rc<Self>, idx: usize, child: &Rc<Self>) -> bool {
+        let points_back = child.parent().is_some_and(|parent| Rc::ptr_eq(&parent, self));
+        points_back && self.children.borrow().get(idx).is_some_and(|node| Rc::ptr_eq(node, child))
+    }
+
+    /// checks the links on the way up to the root and in the whole subtree, without looking at values
+    pub fn validate_links(self: &Rc<Self>) -> Result<(), InvariantError> {
+        let mut seen = BTreeSet::new();
+        let mut current = Rc::clone(self);
+        loop {
+            if !seen.insert(Rc::as_ptr(&current)) {
+                return Err(InvariantError::Cycle);
+            }
+            let parent = current.parent.borrow().clone();
+            match parent.upgrade() {
+                Some(parent) if parent.children.borrow().iter().any(|child| Rc::ptr_eq(child, &current)) => current = parent,
+                Some(_) => return Err(InvariantError::ParentMismatch),
+                // a root has a parent link that never pointed anywhere
+                None if Weak::ptr_eq(&parent, &Weak::new()) => break,
+                None => return Err(InvariantError::DanglingParent),
+            }
+        }
+
+        let mut stack = vec![Rc::clone(self)];
+        while let Some(node) = stack.pop() {
+            for child in node.children.borrow().iter() {
+                if !child.parent().is_some_and(|parent| Rc::ptr_eq(&parent, &node)) {
+                    return Err(InvariantError::ParentMismatch);
+                }
+                if !seen.insert(Rc::as_ptr(child)) {
+                    return Err(InvariantError::Cycle);
+                }
+                stack.push(Rc::clone(child));
+            }
+        }
+        Ok(())
+    }
+
+    /// runs `f` on the index of the tree this node is part of, if it has one
+    fn update_index(&self, f: impl FnOnce(&mut ValueIndex<T>)) {
+        let root = self.parent().map(|parent| parent.get_root());
+        let mut index = root.as_deref().unwrap_or(self).index.borrow_mut();
+        if let Some(index) = index.as_mut() {
+            f(index);
+        }
+    }
+
+    /// drops the index of the tree this node is part of
+    fn invalidate_index(&self) {
+        match self.parent() {
+            Some(parent) => *parent.get_root().index.borrow_mut() = None,
+            None => *self.index.borrow_mut() = None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn add_child_indexed(self: &Rc<Self>, child: &Rc<Self>) -> Result<(), AddError> where T: Hash + Eq {
+        let root = self.get_root();
+        if root.allows_duplicates() || child.parent().is_some() || Rc::ptr_eq(&root, child) {
+            return self.add_child(child);
+        }
+
+        let mut index = root.index.borrow_mut();
+        let index = index.get_or_insert_with(|| {
+            let mut index = ValueIndex { counts: BTreeMap::new(), hash: hash_value };
+            index.count(&root, true);
+            index
+        });
+
+        let mut hashes = vec![];
+        child.visit(&mut |node| hashes.push(hash_value(&*node.value())));
+        // a known hash is either a duplicate or a collision, only then the values are compared
+        if hashes.iter().any(|hash| index.counts.contains_key(hash)) && !root.unique_nodes(child) {
+            return Err(AddError::DuplicateValue);
+        }
+        if child.allows_duplicates() {
+            let mut sorted = hashes.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            if sorted.len() < hashes.len() && child.has_duplicate_values() {
+                return Err(AddError::DuplicateValue);
+            }
+        }
+
+        let idx = self.children.borrow().len();
+        self.attach(idx, child);
+        for hash in hashes {
+            *index.counts.entry(hash).or_insert(0) += 1;
+        }
+        Ok(())
+    }
+
+    pub fn retain_paths_to(self: &Rc<Self>, keep: &impl Fn(&T) -> bool) {
+        let dropped = self.retain_paths(keep);
+        self.forget_in_index(&dropped);
+    }
+
+    /// drops every subtree without a kept node, bottom-up so a child with children left leads to a kept node
+    fn retain_paths(self: &Rc<Self>, keep: &impl Fn(&T) -> bool) -> Vec<Rc<Self>> {
+        let mut dropped = vec![];
+        self.visit_postorder(&mut |node| {
+            node.children.borrow_mut().retain(|child| {
+                let on_path = keep(&child.value()) || !child.children.borrow().is_empty();
+                if !on_path {
+                    *child.parent.borrow_mut() = Weak::new();
+                    dropped.push(Rc::clone(child));
+                }
+                on_path
+            });
+        });
+        dropped
+    }
+
+    /// takes the subtrees that were just cut off below this node out of the index of its tree
+    fn forget_in_index(&self, removed: &[Rc<Self>]) {
+        self.update_index(|index| removed.iter().for_each(|node| index.count(node, false)));
+    }
+
+    /// replaces the children without any value checks, fixing up the parent links on both sides
+    pub fn replace_children(self: &Rc<Self>, children: Vec<Rc<Self>>) {
+        let mut edited: Vec<Rc<Self>> = Vec::with_capacity(children.len());
+        for child in children {
+            if !edited.iter().any(|node| Rc::ptr_eq(node, &child)) {
+                edited.push(child);
+            }
+        }
+
+        for old in self.children.take() {
+            if !edited.iter().any(|node| Rc::ptr_eq(node, &old)) {
+                *old.parent.borrow_mut() = Weak::new();
+            }
+        }
+        for child in &edited {
+            // children taken from elsewhere leave their old parent first
+            child.detach();
             *child.parent.borrow_mut() = Rc::downgrade(self);
-            true
-        } else {
-            false
+        }
+        debug_assert!(edited.iter().all(|child| child.parent().is_some_and(|parent| Rc::ptr_eq(&parent, self))),
+            "edited children must point back at their parent");
+        *self.children.borrow_mut() = edited;
+        self.invalidate_index();
+    }
+
+    pub fn drain_children(&self) -> Vec<Rc<Self>> {
+        let children = core::mem::take(&mut *self.children.borrow_mut());
+        for child in &children {
+            *child.parent.borrow_mut() = Weak::new();
+        }
+        self.forget_in_index(&children);
+        children
+    }
+
+    /// copies the shape of the subtree, passing every value through `f`
+    pub fn map<U>(&self, f: &impl Fn(&T) -> U) -> Rc<RawNode<U>> {
+        let copy = Rc::new(RawNode::new(f(&self.value())));
+        let mut stack = vec![(self.children.borrow().clone(), Rc::clone(&copy))];
+        while let Some((originals, parent)) = stack.pop() {
+            for original in originals {
+                let child_copy = Rc::new(RawNode::new(f(&original.value())));
+                *child_copy.parent.borrow_mut() = Rc::downgrade(&parent);
+                parent.children.borrow_mut().push(Rc::clone(&child_copy));
+                stack.push((original.children.borrow().clone(), child_copy));
+            }
+        }
+        copy
+    }
+
+    /// drops every child subtree whose root fails `keep`, top-down so dropped subtrees are not visited
+    pub fn prune(self: &Rc<Self>, keep: &impl Fn(&T) -> bool) {
+        let mut dropped = vec![];
+        self.visit(&mut |node| {
+            node.children.borrow_mut().retain(|child| {
+                let kept = keep(&child.value());
+                if !kept {
+                    *child.parent.borrow_mut() = Weak::new();
+                    dropped.push(Rc::clone(child));
+                }
+                kept
+            });
+        });
+        self.forget_in_index(&dropped);
+    }
+
+    /// walks the subtree in pre-order, passing every node with its depth relative to this node
+    pub fn visit_controlled(self: &Rc<Self>, f: &mut impl FnMut(&Rc<Self>, usize) -> WalkControl) {
+        let mut stack = vec![(Rc::clone(self), 0)];
+        while let Some((node, depth)) = stack.pop() {
+            match f(&node, depth) {
+                WalkControl::Continue => {
+                    stack.extend(node.children.borrow().iter().rev().map(|child| (Rc::clone(child), depth + 1)));
+                }
+                WalkControl::SkipSubtree => {}
+                WalkControl::Stop => return,
+            }
+        }
+    }
+
+    /// returns the first node of the subtree in pre-order that matches `pred`
+    fn find_first(self: &Rc<Self>, mut pred: impl FnMut(&Rc<Self>) -> bool) -> Option<Rc<Self>> {
+        let mut found = None;
+        self.visit_controlled(&mut |node, _| {
+            if pred(node) {
+                found = Some(Rc::clone(node));
+                WalkControl::Stop
+            } else {
+                WalkControl::Continue
+            }
+        });
+        found
+    }
+
+    pub fn find_by(self: &Rc<Self>, pred: &impl Fn(&T) -> bool) -> Option<Rc<Self>> {
+        self.find_first(|node| pred(&node.value()))
+    }
+
+    pub fn find_all_by(self: &Rc<Self>, pred: &impl Fn(&T) -> bool, found: &mut Vec<Rc<Self>>) {
+        self.visit(&mut |node| {
+            if pred(&node.value()) {
+                found.push(Rc::clone(node));
+            }
+        });
+    }
+
+    pub fn visit(self: &Rc<Self>, f: &mut impl FnMut(&Rc<Self>)) {
+        let mut stack = vec![Rc::clone(self)];
+        while let Some(node) = stack.pop() {
+            f(&node);
+            stack.extend(node.children.borrow().iter().rev().cloned());
+        }
+    }
+
+    pub fn visit_postorder(self: &Rc<Self>, f: &mut impl FnMut(&Rc<Self>)) {
+        let mut stack = vec![(Rc::clone(self), 0)];
+        while let Some((node, next_child)) = stack.last_mut() {
+            let child = node.children.borrow().get(*next_child).cloned();
+            if let Some(child) = child {
+                *next_child += 1;
+                stack.push((child, 0));
+            } else if let Some((node, _)) = stack.pop() {
+                f(&node);
+            }
+        }
+    }
+
+    pub fn len(self: &Rc<Self>) -> usize {
+        let mut len = 0;
+        self.visit(&mut |_| len += 1);
+        len
+    }
+
+    pub fn depth(&self) -> usize {
+        let mut depth = 0;
+        let mut current = self.parent();
+        while let Some(node) = current {
+            depth += 1;
+            current = node.parent();
+        }
+        depth
+    }
+
+    pub fn height(self: &Rc<Self>) -> usize {
+        let mut height = 0;
+        self.visit_controlled(&mut |_, depth| {
+            height = height.max(depth);
+            WalkControl::Continue
+        });
+        height
+    }
+
+    /// counts the children of the level above instead of collecting the last level
+    pub fn count_at_depth(self: &Rc<Self>, depth: usize) -> usize {
+        let Some(above) = depth.checked_sub(1) else {
+            return 1;
         };
+        let mut level = vec![];
+        self.nodes_at_depth(above, &mut level);
+        level.iter().map(|node| node.children.borrow().len()).sum()
     }
 
-    pub fn find(self: &Rc<Self>, value: &T) -> Option<Rc<Self>> {
-        if self.value() == value {
-            Some(self.clone())
-        } else {
-            self.children.borrow().iter().find_map(|node| Self::find(node, value))
+    pub fn leaves(self: &Rc<Self>, leaves: &mut Vec<Rc<Self>>) {
+        self.visit(&mut |node| {
+            if node.children.borrow().is_empty() {
+                leaves.push(Rc::clone(node));
+            }
+        });
+    }
+
+    /// only descends as far as `depth`, level by level
+    pub fn nodes_at_depth(self: &Rc<Self>, depth: usize, nodes: &mut Vec<Rc<Self>>) {
+        let mut level = vec![Rc::clone(self)];
+        for _ in 0..depth {
+            if level.is_empty() {
+                break;
+            }
+            level = level.iter().flat_map(|node| node.children.borrow().clone()).collect();
         }
+        nodes.extend(level);
     }
 
-    pub fn remove_node(self: &Rc<Self>, value: &T) -> Option<Rc<Self>> {
-        if let Some(node) = self.find(value) {
-            if let Some(parent) = node.parent.borrow().upgrade() {
-                let mut vec = parent.children.borrow_mut();
-                let idx = vec.iter().position(|node| node.value() == value).unwrap();
-                return Some(vec.remove(idx));
+    pub fn retain_children(&self, keep: &impl Fn(&T) -> bool) {
+        let mut dropped = vec![];
+        self.children.borrow_mut().retain(|child| {
+            let kept = keep(&child.value());
+            if !kept {
+                *child.parent.borrow_mut() = Weak::new();
+                dropped.push(Rc::clone(child));
             }
+            kept
+        });
+        self.forget_in_index(&dropped);
+    }
+
+    pub fn detach(self: &Rc<Self>) -> bool {
+        self.position().map(|(parent, idx)| parent.remove_child_at(idx)).is_some()
+    }
+
+    fn remove_child_at(&self, idx: usize) -> Rc<Self> {
+        let removed = self.children.borrow_mut().remove(idx);
+        *removed.parent.borrow_mut() = Weak::new();
+        self.forget_in_index(core::slice::from_ref(&removed));
+        removed
+    }
+
+    /// lifts the deeper node until both are at the same depth, then lifts both until they meet
+    pub fn lowest_common_ancestor(self: &Rc<Self>, other: &Rc<Self>) -> Option<Rc<Self>> {
+        let (mut mine, mut theirs) = (Rc::clone(self), Rc::clone(other));
+        let (mut my_depth, mut their_depth) = (self.depth(), other.depth());
+        while my_depth > their_depth {
+            mine = mine.parent()?;
+            my_depth -= 1;
         }
-        None
+        while their_depth > my_depth {
+            theirs = theirs.parent()?;
+            their_depth -= 1;
+        }
+        while !Rc::ptr_eq(&mine, &theirs) {
+            mine = mine.parent()?;
+            theirs = theirs.parent()?;
+        }
+        Some(mine)
+    }
+
+    pub fn contains_node(self: &Rc<Self>, other: &Rc<Self>) -> bool {
+        let mut current = Some(Rc::clone(other));
+        while let Some(node) = current {
+            if Rc::ptr_eq(self, &node) {
+                return true;
+            }
+            current = node.parent();
+        }
+        false
     }
 
     pub fn get_root(self: &Rc<Self>) -> Rc<Self> {
-        return if self.parent().is_none() {
-            self.clone()
+        let mut current = Rc::clone(self);
+        while let Some(parent) = current.parent() {
+            current = parent;
+        }
+        current
+    }
+
+    fn subtree_nodes(self: &Rc<Self>) -> Vec<Rc<Self>> {
+        let mut nodes = vec![];
+        self.visit(&mut |node| nodes.push(Rc::clone(node)));
+        nodes
+    }
+}
+
+// everything that looks at values, including the uniqueness check when adding
+#[allow(unused)]
+impl<T> RawNode<T> where T: PartialEq {
+    pub fn add_child(self: &Rc<Self>, child: &Rc<Self>) -> Result<(), AddError> {
+        let idx = self.children.borrow().len();
+        self.insert_child(idx, child)
+    }
+
+    pub fn insert_child(self: &Rc<Self>, idx: usize, child: &Rc<Self>) -> Result<(), AddError> {
+        self.can_adopt(child)?;
+        self.link_child(idx, child);
+        Ok(())
+    }
+
+    pub fn move_to(self: &Rc<Self>, new_parent: &Rc<Self>) -> Result<(), AddError> {
+        if self.contains_node(new_parent) {
+            return Err(AddError::WouldCreateCycle);
+        }
+
+        let old_position = self.position();
+        self.detach();
+        let moved = new_parent.add_child(self);
+        if let (Err(_), Some((parent, idx))) = (moved, old_position) {
+            parent.link_child(idx, self);
+        }
+        moved
+    }
+
+    fn can_adopt(self: &Rc<Self>, child: &Rc<Self>) -> Result<(), AddError> {
+        if child.contains_node(self) {
+            return Err(AddError::WouldCreateCycle);
+        }
+        if child.parent().is_some() {
+            return Err(AddError::AlreadyHasParent);
+        }
+
+        let root = self.get_root();
+        // only a child from a tree that allowed duplicates can repeat its own values
+        let repeats_itself = child.allows_duplicates() && child.has_duplicate_values();
+        if root.allows_duplicates() || (!repeats_itself && root.unique_nodes(child)) {
+            Ok(())
         } else {
-            Self::get_root(&self.parent.borrow().upgrade().unwrap())
+            Err(AddError::DuplicateValue)
+        }
+    }
+
+    /// true if the tree keeps values unique and another node already has `value`
+    pub fn collides_with(self: &Rc<Self>, value: &T) -> bool {
+        let root = self.get_root();
+        !root.allows_duplicates() && root.find_first(|node| !Rc::ptr_eq(node, self) && *node.value() == *value).is_some()
+    }
+
+    pub fn find(self: &Rc<Self>, value: &T) -> Option<Rc<Self>> {
+        self.find_first(|node| *node.value() == *value)
+    }
+
+    pub fn find_all(self: &Rc<Self>, value: &T, found: &mut Vec<Rc<Self>>) {
+        self.find_all_by(&|node_value| *node_value == *value, found);
+    }
+
+    /// the stack of the walk doubles as the path, every entry remembers the next child to descend into
+    pub fn find_path(self: &Rc<Self>, value: &T, path: &mut Vec<Rc<Self>>) -> bool {
+        if *self.value() == *value {
+            path.push(Rc::clone(self));
+            return true;
+        }
+
+        let mut stack = vec![(Rc::clone(self), 0)];
+        while let Some((node, next_child)) = stack.last_mut() {
+            let child = node.children.borrow().get(*next_child).cloned();
+            match child {
+                Some(child) if *child.value() == *value => {
+                    path.extend(stack.into_iter().map(|(node, _)| node));
+                    path.push(child);
+                    return true;
+                }
+                Some(child) => {
+                    *next_child += 1;
+                    stack.push((child, 0));
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        false
+    }
+
+    pub fn remove_node(self: &Rc<Self>, value: &T) -> Option<Rc<Self>> {
+        let (parent, idx) = if *self.value() == *value {
+            self.position()?
+        } else {
+            self.find_child_position(value)?
         };
+
+        Some(parent.remove_child_at(idx))
     }
 
-    fn as_array(self: &Rc<Self>, mut elements: &mut Vec<Rc<Self>>, parent: &Rc<Self>) {
-        if self.parent().is_none() || (self.parent().is_some() && &self.parent().unwrap() == parent) {
-            elements.push(self.clone());
+    /// removes the first node with `value` and puts its children in its place
+    pub fn remove_node_promote(self: &Rc<Self>, value: &T) -> Option<Rc<Self>> {
+        let (parent, idx) = if *self.value() == *value {
+            self.position()?
+        } else {
+            self.find_child_position(value)?
+        };
+
+        let removed = parent.remove_child_at(idx);
+        let children = removed.children.take();
+        let promoted = children.len();
+        for child in &children {
+            *child.parent.borrow_mut() = Rc::downgrade(&parent);
         }
+        // the removal took the whole subtree out of the index, the promoted children are counted back in
+        parent.update_index(|index| children.iter().for_each(|child| index.count(child, true)));
+        parent.children.borrow_mut().splice(idx..idx, children);
+        debug_assert!((idx..idx + promoted).all(|at| parent.children.borrow()[at].parent().is_some_and(|p| Rc::ptr_eq(&p, &parent))),
+            "promoted children must point back at their new parent");
+        Some(removed)
+    }
+
+    /// finds the first descendant with `value` in pre-order, returned as its parent and index
+    fn find_child_position(self: &Rc<Self>, value: &T) -> Option<(Rc<Self>, usize)> {
+        let mut stack = vec![(Rc::clone(self), 0)];
+        while let Some((node, next_child)) = stack.last_mut() {
+            let child = node.children.borrow().get(*next_child).cloned();
+            match child {
+                Some(child) if *child.value() == *value => return Some((Rc::clone(node), *next_child)),
+                Some(child) => {
+                    *next_child += 1;
+                    stack.push((child, 0));
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        None
+    }
 
-        for child in self.children.borrow().iter() {
-            elements.push(child.clone());
+    /// walks both subtrees in lockstep, comparing values and the number of children
+    pub fn structural_eq(self: &Rc<Self>, other: &Rc<Self>) -> bool {
+        let mut stack = vec![(Rc::clone(self), Rc::clone(other))];
+        while let Some((mine, theirs)) = stack.pop() {
+            if Rc::ptr_eq(&mine, &theirs) {
+                continue;
+            }
+            let my_children = mine.children.borrow();
+            let their_children = theirs.children.borrow();
+            if *mine.value() != *theirs.value() || my_children.len() != their_children.len() {
+                return false;
+            }
+            stack.extend(my_children.iter().cloned().zip(their_children.iter().cloned()));
         }
-        self.children.borrow().iter().for_each(|child| Self::as_array(child, elements, parent));
+        true
+    }
+
+    /// true if two nodes of the subtree share a value, only meaningful once the links are valid
+    pub fn has_duplicate_values(self: &Rc<Self>) -> bool {
+        let nodes = self.subtree_nodes();
+        nodes.iter().enumerate().any(|(idx, node)| nodes[idx + 1..].iter().any(|other| *other.value() == *node.value()))
     }
 
     pub fn unique_nodes(self: &Rc<Self>, other: &Rc<Self>) -> bool {
-        let mut my_nodes = vec![];
-        let mut other_nodes = vec![];
-        self.as_array(&mut my_nodes, self);
-        other.as_array(&mut other_nodes, self);
-        let my_values: Vec<&T> = my_nodes.iter().map(|n| n.value()).collect();
-        let other_values: Vec<&T> = other_nodes.iter().map(|n| n.value()).collect();
+        let my_nodes = self.subtree_nodes();
+        let other_nodes = other.subtree_nodes();
 
-        !other_values.iter().any(|o| my_values.contains(o))
+        !other_nodes.iter().any(|o| my_nodes.iter().any(|m| *m.value() == *o.value()))
     }
 }
 
+/// tears the subtree down with an explicit stack, nested [Rc]s would otherwise drop recursively
+impl<T> Drop for RawNode<T> {
+    fn drop(&mut self) {
+        let mut stack = core::mem::take(self.children.get_mut());
+        while let Some(child) = stack.pop() {
+            // children still referenced elsewhere live on with their subtree
+            match Rc::try_unwrap(child) {
+                Ok(mut child) => stack.append(child.children.get_mut()),
+                // so they don't keep pointing at a dropped parent
+                Err(child) => *child.parent.borrow_mut() = Weak::new(),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn hash_value<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
 impl<T> PartialEq for RawNode<T> where T: PartialEq {
     fn eq(&self, other: &Self) -> bool {
         self.value == other.value
@@ -0,0 +1,167 @@
+use alloc::rc::Rc;
+use crate::base::RawNode;
+use crate::error::{AddError, CursorError};
+use crate::Node;
+
+/// a movable position in a tree for scripted navigation and edits, created by [`Node::cursor()`]
+///
+/// moves that aren't possible return an error and leave the cursor where it was,
+/// so steps can be chained with `?`
+/// ## Example
+/// ```
+/// use a_ntree::{tree, CursorError};
+/// let root = tree! { 1 => [2 => [4], 3] };
+/// let mut cursor = root.cursor();
+///
+/// cursor.down(0)?.down(0)?;
+/// assert_eq!(cursor.node().value(), 4);
+/// cursor.up()?.next_sibling()?;
+/// assert_eq!(cursor.node().value(), 3);
+/// assert_eq!(cursor.next_sibling().unwrap_err(), CursorError::NoSuchNode);
+/// # Ok::<(), CursorError>(())
+/// ```
+#[derive(Debug)]
+pub struct Cursor<T> {
+    current: Node<T>,
+}
+
+impl<T> Node<T> {
+    /// creates a [Cursor] that starts at this [Node]
+    #[must_use]
+    pub fn cursor(&self) -> Cursor<T> {
+        Cursor { current: Self::from(&self.pointer) }
+    }
+}
+
+impl<T> Cursor<T> {
+    /// returns the [Node] the cursor is on
+    #[must_use]
+    pub const fn node(&self) -> &Node<T> {
+        &self.current
+    }
+
+    /// returns the [Node] the cursor is on and drops the cursor
+    #[must_use]
+    pub fn into_node(self) -> Node<T> {
+        self.current
+    }
+
+    fn go(&mut self, node: Option<Node<T>>) -> Result<&mut Self, CursorError> {
+        self.current = node.ok_or(CursorError::NoSuchNode)?;
+        Ok(self)
+    }
+
+    /// moves to the child at `index`
+    /// ## Errors
+    /// [`CursorError::NoSuchNode`] if there are not enough children
+    pub fn down(&mut self, index: usize) -> Result<&mut Self, CursorError> {
+        let child = self.current.child_at(index);
+        self.go(child)
+    }
+
+    /// moves to the parent
+    /// ## Errors
+    /// [`CursorError::NoSuchNode`] on the root
+    pub fn up(&mut self) -> Result<&mut Self, CursorError> {
+        let parent = self.current.parent();
+        self.go(parent)
+    }
+
+    /// moves to the next sibling
+    /// ## Errors
+    /// [`CursorError::NoSuchNode`] on the last child or the root
+    pub fn next_sibling(&mut self) -> Result<&mut Self, CursorError> {
+        let sibling = self.current.next_sibling();
+        self.go(sibling)
+    }
+
+    /// moves to the previous sibling
+    /// ## Errors
+    /// [`CursorError::NoSuchNode`] on the first child or the root
+    pub fn prev_sibling(&mut self) -> Result<&mut Self, CursorError> {
+        let sibling = self.current.prev_sibling();
+        self.go(sibling)
+    }
+
+    /// moves to the root of the tree, which is always possible
+    pub fn root(&mut self) -> &mut Self {
+        self.current = self.current.get_root();
+        self
+    }
+
+    /// removes the [Node] under the cursor together with its subtree and returns it
+    ///
+    /// the cursor moves on to the next sibling, or the previous one if it was the last child, or the parent
+    /// ## Example
+    /// ```
+    /// use a_ntree::tree;
+    /// let root = tree! { 1 => [2, 3] };
+    /// let mut cursor = root.cursor();
+    /// cursor.down(1).unwrap();
+    ///
+    /// assert_eq!(cursor.remove_here().unwrap().value(), 3);
+    /// assert_eq!(cursor.node().value(), 2);
+    /// cursor.remove_here().unwrap();
+    /// assert_eq!(cursor.node(), &root);
+    /// assert!(cursor.remove_here().is_err());
+    /// ```
+    /// ## Errors
+    /// [`CursorError::AtRoot`] on the root
+    pub fn remove_here(&mut self) -> Result<Node<T>, CursorError> {
+        let parent = self.current.parent().ok_or(CursorError::AtRoot)?;
+        let next = self.current.next_sibling()
+            .or_else(|| self.current.prev_sibling())
+            .unwrap_or(parent);
+        let removed = core::mem::replace(&mut self.current, next);
+        removed.pointer.detach();
+        Ok(removed)
+    }
+}
+
+impl<T> Cursor<T> where T: PartialEq {
+    /// moves to the first [Node] of the whole tree with a value, in pre-order from the root
+    /// ## Errors
+    /// [`CursorError::NotFound`] if no Node has the value
+    pub fn seek(&mut self, value: &T) -> Result<&mut Self, CursorError> {
+        self.current = self.current.get_root().find(value).ok_or(CursorError::NotFound)?;
+        Ok(self)
+    }
+
+    fn insert_sibling(&mut self, value: T, offset: usize) -> Result<&mut Self, CursorError> {
+        let (parent, idx) = self.current.pointer.position().ok_or(CursorError::AtRoot)?;
+        let sibling = Rc::new(RawNode::new(value));
+        parent.insert_child(idx + offset, &sibling).map_err(CursorError::Rejected)?;
+        Ok(self)
+    }
+
+    /// adds a value as a new sibling directly before the [Node] under the cursor, the cursor stays where it is
+    /// ## Example
+    /// ```
+    /// use a_ntree::{tree, AddError, CursorError};
+    /// let root = tree! { 1 => [3] };
+    /// let mut cursor = root.cursor();
+    ///
+    /// cursor.down(0).unwrap().insert_before(2).unwrap().insert_after(4).unwrap();
+    /// assert_eq!(root.children_values(), vec![2, 3, 4]);
+    /// assert_eq!(cursor.insert_before(1).unwrap_err(), CursorError::Rejected(AddError::DuplicateValue));
+    /// ```
+    /// ## Errors
+    /// [`CursorError::AtRoot`] on the root and [`CursorError::Rejected`] if the tree doesn't accept the value
+    pub fn insert_before(&mut self, value: T) -> Result<&mut Self, CursorError> {
+        self.insert_sibling(value, 0)
+    }
+
+    /// adds a value as a new sibling directly after the [Node] under the cursor, see [`Cursor::insert_before()`]
+    /// ## Errors
+    /// [`CursorError::AtRoot`] on the root and [`CursorError::Rejected`] if the tree doesn't accept the value
+    pub fn insert_after(&mut self, value: T) -> Result<&mut Self, CursorError> {
+        self.insert_sibling(value, 1)
+    }
+
+    /// replaces the value of the [Node] under the cursor and returns the old one
+    /// ## Errors
+    /// [`CursorError::Rejected`] if another Node of the tree already has the value
+    pub fn replace_value(&mut self, value: T) -> Result<T, CursorError> {
+        self.current.try_set_value(value).map_err(|_| CursorError::Rejected(AddError::DuplicateValue))
+    }
+}
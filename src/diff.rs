@@ -0,0 +1,14 @@
+use alloc::vec::Vec;
+
+/// the value-level difference between two trees, created by [`Node::diff()`](crate::Node::diff)
+///
+/// the structure of the trees is ignored, each value is listed once in pre-order
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TreeDiff<T> {
+    /// values that only appear in the calling tree
+    pub only_in_self: Vec<T>,
+    /// values that only appear in the other tree
+    pub only_in_other: Vec<T>,
+    /// values that appear in both trees
+    pub in_both: Vec<T>,
+}
@@ -0,0 +1,134 @@
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter, Result, Write};
+use crate::base::RawNode;
+use crate::Node;
+
+/// nodes waiting to be rendered, each with the indentation of its line, whether it is the last child and its depth
+type Pending<T> = Vec<(Rc<RawNode<T>>, String, bool, usize)>;
+
+/// how [`Node::render()`] draws a tree
+///
+/// the default uses Unicode box drawing glyphs and renders every level, like the [Display] implementation of [Node]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RenderStyle {
+    ascii: bool,
+    max_depth: Option<usize>,
+}
+
+impl RenderStyle {
+    /// draws the branches with `├──`, `└──` and `│`
+    #[must_use]
+    pub const fn unicode() -> Self {
+        Self { ascii: false, max_depth: None }
+    }
+
+    /// draws the branches with `|--`, `` `-- `` and `|`
+    #[must_use]
+    pub const fn ascii() -> Self {
+        Self { ascii: true, max_depth: None }
+    }
+
+    /// stops below `depth` levels, hidden children are replaced by a single `...` line
+    #[must_use]
+    pub const fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// the connector of a line and the indentation below it
+    const fn glyphs(self, last: bool) -> (&'static str, &'static str) {
+        match (self.ascii, last) {
+            (false, false) => ("├── ", "│   "),
+            (false, true) => ("└── ", "    "),
+            (true, false) => ("|-- ", "|   "),
+            (true, true) => ("`-- ", "    "),
+        }
+    }
+}
+
+impl<T> Node<T> where T: Display {
+    /// renders the subtree of a [Node] as an indented tree in the given [`RenderStyle`], one Node per line
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, RenderStyle};
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// child.add_leaf(21);
+    /// root.add_leaf(30);
+    ///
+    /// assert_eq!(root.render(RenderStyle::ascii()), "10\n|-- 20\n|   `-- 21\n`-- 30");
+    /// assert_eq!(root.render(RenderStyle::unicode().max_depth(1)), "10\n├── 20\n│   └── ...\n└── 30");
+    /// ```
+    #[must_use]
+    pub fn render(&self, style: RenderStyle) -> String {
+        let mut rendered = String::new();
+        let _ = write_tree(&mut rendered, &self.pointer, style);
+        rendered
+    }
+}
+
+/// renders the subtree of a [Node] as an indented tree, one Node per line
+///
+/// see [`Node::render()`] for ASCII glyphs or a depth limit
+/// ## Example
+/// ```
+/// use a_ntree::Node;
+/// let root = Node::new(10);
+/// let child = Node::new(20);
+/// root.add_child(&child);
+/// child.add_leaf(21);
+/// root.add_leaf(30);
+///
+/// assert_eq!(root.to_string(), "10\n├── 20\n│   └── 21\n└── 30");
+/// assert_eq!(Node::new(10).to_string(), "10");
+/// ```
+impl<T> Display for Node<T> where T: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_tree(f, &self.pointer, RenderStyle::default())
+    }
+}
+
+fn write_tree<T>(out: &mut impl Write, root: &Rc<RawNode<T>>, style: RenderStyle) -> Result
+where
+    T: Display,
+{
+    write!(out, "{}", root.value())?;
+
+    let mut stack = vec![];
+    write_children(out, &mut stack, root, "", 0, style)?;
+
+    while let Some((node, prefix, last, depth)) = stack.pop() {
+        let (connector, indent) = style.glyphs(last);
+        write!(out, "\n{prefix}{connector}{}", node.value())?;
+        write_children(out, &mut stack, &node, &(prefix + indent), depth, style)?;
+    }
+    Ok(())
+}
+
+/// pushes the children of `node` in reverse, so the first child is rendered first
+///
+/// at the depth limit of `style` a single `...` line is written in place of the children
+fn write_children<T>(
+    out: &mut impl Write,
+    stack: &mut Pending<T>,
+    node: &Rc<RawNode<T>>,
+    prefix: &str,
+    depth: usize,
+    style: RenderStyle,
+) -> Result {
+    let children = node.children().borrow();
+    if style.max_depth.is_some_and(|max| depth >= max) {
+        if !children.is_empty() {
+            write!(out, "\n{prefix}{}...", style.glyphs(true).0)?;
+        }
+        return Ok(());
+    }
+    for (idx, child) in children.iter().enumerate().rev() {
+        stack.push((Rc::clone(child), String::from(prefix), idx + 1 == children.len(), depth + 1));
+    }
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use core::fmt::{Display, Write};
+use crate::base::RawNode;
+use crate::Node;
+
+impl<T> Node<T> where T: Display {
+    /// renders the subtree of a [Node] as a Graphviz DOT digraph
+    ///
+    /// nodes are labeled with the [Display] text of `T` and get the ids `n0`, `n1`, .. in pre-order,
+    /// so the same tree always renders the same text. Pipe it into `dot -Tpng` to get a picture
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// root.add_leaf(20);
+    ///
+    /// assert_eq!(root.to_dot(), "digraph {\n    n0 [label=\"10\"];\n    n1 [label=\"20\"];\n    n0 -> n1;\n}\n");
+    /// ```
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        let mut next_id = 0;
+        let mut stack = vec![(Rc::clone(&self.pointer), None)];
+
+        while let Some((node, parent)) = stack.pop() {
+            let id = next_id;
+            next_id += 1;
+            write_label(&node, id, &mut dot);
+            if let Some(parent) = parent {
+                let _ = writeln!(dot, "    n{parent} -> n{id};");
+            }
+            stack.extend(node.children().borrow().iter().rev().map(|child| (Rc::clone(child), Some(id))));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn write_label<T>(node: &RawNode<T>, id: usize, dot: &mut String) where T: Display {
+    let _ = write!(dot, "    n{id} [label=\"");
+    for c in node.value().to_string().chars() {
+        match c {
+            '"' => dot.push_str("\\\""),
+            '\\' => dot.push_str("\\\\"),
+            '\n' => dot.push_str("\\n"),
+            c => dot.push(c),
+        }
+    }
+    dot.push_str("\"];\n");
+}
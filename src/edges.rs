@@ -0,0 +1,72 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::vec;
+use std::vec::Vec;
+use crate::error::EdgeError;
+use crate::Node;
+
+impl<T> Node<T> where T: Eq + Hash + Clone {
+    /// assembles a tree from `(parent, child)` pairs, like the rows of a table with a parent column
+    ///
+    /// the edges can come in any order, children keep the order of their edges.
+    /// The value that is never a child becomes the root, values are unique by construction
+    /// ## Example
+    /// ```
+    /// use a_ntree::{EdgeError, Node};
+    /// let root = Node::from_edges([("b", "b1"), ("root", "a"), ("root", "b")]).unwrap();
+    ///
+    /// assert_eq!(root.value(), "root");
+    /// assert_eq!(root.children_values(), vec!["a", "b"]);
+    /// assert_eq!(root.find(&"b").unwrap().children_values(), vec!["b1"]);
+    ///
+    /// assert_eq!(Node::from_edges([(1, 2), (3, 4)]), Err(EdgeError::MultipleRoots(vec![1, 3])));
+    /// assert_eq!(Node::from_edges([(1, 2), (3, 2)]), Err(EdgeError::MultipleParents(2)));
+    /// ```
+    /// ## Errors
+    /// returns why the edges don't form exactly one tree
+    pub fn from_edges(edges: impl IntoIterator<Item = (T, T)>) -> Result<Self, EdgeError<T>> {
+        // every value in order of its first edge
+        let mut values = vec![];
+        let mut children: HashMap<T, Vec<T>> = HashMap::new();
+        let mut has_parent = HashMap::new();
+        for (parent, child) in edges {
+            for value in [&parent, &child] {
+                if let Entry::Vacant(entry) = has_parent.entry(value.clone()) {
+                    entry.insert(false);
+                    values.push(value.clone());
+                }
+            }
+            if has_parent.insert(child.clone(), true) == Some(true) {
+                return Err(EdgeError::MultipleParents(child));
+            }
+            children.entry(parent).or_default().push(child);
+        }
+
+        let mut roots: Vec<T> = values.iter().filter(|value| !has_parent[*value]).cloned().collect();
+        let root = match roots.len() {
+            0 if values.is_empty() => return Err(EdgeError::Empty),
+            0 => return Err(EdgeError::NoRoot),
+            1 => roots.remove(0),
+            _ => return Err(EdgeError::MultipleRoots(roots)),
+        };
+
+        let tree = Self::new(root.clone());
+        let mut reached = HashSet::from([root.clone()]);
+        let mut stack = vec![(Self::from(&tree.pointer), root)];
+        while let Some((node, value)) = stack.pop() {
+            for child in children.remove(&value).unwrap_or_default() {
+                let child_node = Self::new(child.clone());
+                let idx = node.pointer.children().borrow().len();
+                node.pointer.link_child(idx, &child_node.pointer);
+                reached.insert(child.clone());
+                stack.push((child_node, child));
+            }
+        }
+
+        if reached.len() < values.len() {
+            return Err(EdgeError::Orphaned(values.into_iter().filter(|value| !reached.contains(value)).collect()));
+        }
+        Ok(tree)
+    }
+}
@@ -0,0 +1,158 @@
+use alloc::slice;
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::Node;
+
+/// a single step of an edit script created by [`diff()`](crate::diff), values identify the Nodes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit<T> {
+    /// the root takes a new value, roots are matched by position instead of by value
+    Update { old: T, new: T },
+    /// a new leaf with `value` is added at `index` among the children of `parent`
+    Insert { value: T, parent: T, index: usize },
+    /// the Node with `value` is moved together with its subtree to `index` among the children of `parent`
+    Move { value: T, parent: T, index: usize },
+    /// the child of `parent` with `value` is removed together with its subtree
+    Remove { value: T, parent: T },
+}
+
+/// computes the [Edit]s that turn the tree of `old` into the tree of `new`
+///
+/// both trees need unique values, a value that appears in both trees is treated as the same Node.
+/// Edits come in the order they have to be applied with [`Node::apply()`]: first the root, then the removals of subtrees
+/// without a Node of `new`, then inserts and moves in the pre-order of `new` and finally the removals of what was left behind.
+/// Nodes that are already in place produce no edit
+/// ## Example
+/// ```
+/// use a_ntree::{diff, tree, Edit};
+/// let old = tree! { 1 => [2 => [3], 4] };
+/// let new = tree! { 1 => [4 => [3], 5] };
+///
+/// let edits = diff(&old, &new);
+/// assert_eq!(edits, vec![
+///     Edit::Move { value: 4, parent: 1, index: 0 },
+///     Edit::Move { value: 3, parent: 4, index: 0 },
+///     Edit::Insert { value: 5, parent: 1, index: 1 },
+///     Edit::Remove { value: 2, parent: 1 },
+/// ]);
+///
+/// old.apply(&edits).unwrap();
+/// assert!(old.structural_eq(&new));
+/// ```
+#[must_use]
+pub fn diff<T>(old: &Node<T>, new: &Node<T>) -> Vec<Edit<T>> where T: PartialEq + Clone {
+    // every edit is replayed on a copy, so the next ones are computed against the tree as it is at that point
+    let working = old.deep_clone();
+    let mut edits = vec![];
+    let mut record = |edit: Edit<T>| {
+        let applied = working.apply(slice::from_ref(&edit));
+        debug_assert!(applied.is_ok(), "an edit of the script couldn't be replayed");
+        edits.push(edit);
+    };
+
+    if *old.borrow_value() != *new.borrow_value() {
+        record(Edit::Update { old: old.value(), new: new.value() });
+    }
+
+    // subtrees without a Node of `new` go first, so they don't shift the positions of the later edits
+    for edit in removals(&working, new) {
+        record(edit);
+    }
+    for node in new.descendants() {
+        let (Some(parent), Some(index)) = (node.parent(), node.child_index()) else { continue };
+        let (value, parent) = (node.value(), parent.value());
+        match working.find(&value) {
+            Some(current) if current.parent() == working.find(&parent) && current.child_index() == Some(index) => {}
+            Some(_) => record(Edit::Move { value, parent, index }),
+            None => record(Edit::Insert { value, parent, index }),
+        }
+    }
+
+    for edit in removals(&working, new) {
+        record(edit);
+    }
+    edits
+}
+
+/// the removals of every subtree of `working` without a non-root value of `new`, the root is always kept
+fn removals<T>(working: &Node<T>, new: &Node<T>) -> Vec<Edit<T>> where T: PartialEq + Clone {
+    let mut removals = vec![];
+    // the results of the children of a Node are the last entries when it is reached in post-order
+    let mut pending: Vec<(Node<T>, bool)> = vec![];
+    for node in working.iter_dfs_post() {
+        let children = pending.split_off(pending.len() - node.children().len());
+        let kept = node == *working || new.find(&node.borrow_value()).is_some_and(|found| !found.is_root());
+        let keeps_any = kept || children.iter().any(|(_, keeps_any)| *keeps_any);
+        if keeps_any {
+            for (child, _) in children.iter().filter(|(_, keeps_any)| !keeps_any) {
+                removals.push(Edit::Remove { value: child.value(), parent: node.value() });
+            }
+        }
+        pending.push((node, keeps_any));
+    }
+    removals
+}
+
+impl<T> Node<T> where T: PartialEq {
+    /// replays [Edit]s created by [`diff()`](crate::diff) onto the subtree of this [Node], in order
+    ///
+    /// values are looked up below this Node, an [`Edit::Update`] applies to the calling Node itself.
+    /// Moves keep the values of the tree the same, so only the structure is checked for them
+    /// ## Example
+    /// ```
+    /// use a_ntree::{tree, Edit};
+    /// let root = tree! { 1 => [2] };
+    ///
+    /// assert_eq!(root.apply(&[Edit::Insert { value: 3, parent: 2, index: 0 }]), Ok(()));
+    /// assert_eq!(root.find(&2).unwrap().children_values(), vec![3]);
+    ///
+    /// let invalid = [Edit::Remove { value: 3, parent: 2 }, Edit::Move { value: 1, parent: 2, index: 0 }];
+    /// assert_eq!(root.apply(&invalid), Err(1));
+    /// assert!(root.find(&3).is_none());
+    /// ```
+    /// ## Errors
+    /// returns the position of the first edit that doesn't fit the tree, the edits before it stay applied
+    pub fn apply(&self, edits: &[Edit<T>]) -> Result<(), usize> where T: Clone {
+        for (position, edit) in edits.iter().enumerate() {
+            if !self.apply_edit(edit) {
+                return Err(position);
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_edit(&self, edit: &Edit<T>) -> bool where T: Clone {
+        match edit {
+            Edit::Update { old, new } => {
+                let matches = *self.borrow_value() == *old;
+                if matches {
+                    self.set_value(new.clone());
+                }
+                matches
+            }
+            Edit::Insert { value, parent, index } => {
+                self.find(parent).is_some_and(|parent| parent.insert_child_at(*index, &Self::new(value.clone())))
+            }
+            Edit::Move { value, parent, index } => {
+                let (Some(node), Some(parent)) = (self.find(value), self.find(parent)) else { return false };
+                if node.subtree_contains_node(&parent) {
+                    return false;
+                }
+                let stays = node.parent().as_ref() == Some(&parent);
+                if *index > parent.pointer.children().borrow().len() - usize::from(stays) {
+                    return false;
+                }
+                node.pointer.detach();
+                parent.pointer.link_child(*index, &node.pointer);
+                true
+            }
+            Edit::Remove { value, parent } => {
+                // the old Node with the new value of the root is only told apart by its children
+                let child = self.find_all(parent).iter()
+                    .flat_map(Self::children)
+                    .find(|child| *child.borrow_value() == *value);
+                child.is_some_and(|child| self.remove(&child))
+            }
+        }
+    }
+}
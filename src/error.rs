@@ -0,0 +1,152 @@
+use core::fmt::{Display, Formatter, Result};
+
+/// the reason why [`Node::try_add_child()`](crate::Node::try_add_child) rejected a child
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddError {
+    /// a value of the child's subtree is already in the tree
+    DuplicateValue,
+    /// the child is the Node itself or one of its ancestors
+    WouldCreateCycle,
+    /// the child is already part of a tree, detach it first
+    AlreadyHasParent,
+}
+
+impl Display for AddError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            Self::DuplicateValue => "a value of the child is already in the tree",
+            Self::WouldCreateCycle => "the child is the node itself or one of its ancestors",
+            Self::AlreadyHasParent => "the child already has a parent",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AddError {}
+
+/// the reason why [`Node::from_json_str()`](crate::Node::from_json_str) couldn't read a tree,
+/// every position is a byte offset into the input
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonError {
+    /// the input isn't a tree of `{"value":..,"children":[..]}` objects
+    Syntax { position: usize },
+    /// parsing the value with [`FromStr`](core::str::FromStr) failed
+    InvalidValue { position: usize },
+    /// the Node couldn't be added to its parent
+    Rejected { position: usize, error: AddError },
+}
+
+#[cfg(feature = "json")]
+impl Display for JsonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Syntax { position } => write!(f, "unexpected input at byte {position}"),
+            Self::InvalidValue { position } => write!(f, "invalid value at byte {position}"),
+            Self::Rejected { position, error } => write!(f, "node at byte {position} rejected: {error}"),
+        }
+    }
+}
+
+#[cfg(all(feature = "json", feature = "std"))]
+impl std::error::Error for JsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Rejected { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// a broken link found by [`Node::validate()`](crate::Node::validate)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvariantError {
+    /// a child doesn't point back at the Node that holds it as a child
+    ParentMismatch,
+    /// the parent of a Node was dropped without unlinking it
+    DanglingParent,
+    /// a Node is reachable more than once, through a cycle or by being the child of two Nodes
+    Cycle,
+    /// two Nodes share a value although the tree keeps values unique
+    DuplicateValue,
+}
+
+impl Display for InvariantError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            Self::ParentMismatch => "a child doesn't point back at its parent",
+            Self::DanglingParent => "the parent of a node was dropped without unlinking it",
+            Self::Cycle => "a node is reachable more than once",
+            Self::DuplicateValue => "a value appears more than once although the tree keeps values unique",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvariantError {}
+
+/// the reason why a [`Cursor`](crate::Cursor) couldn't move or edit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CursorError {
+    /// there is no Node in the direction of the move
+    NoSuchNode,
+    /// no Node of the tree has the value
+    NotFound,
+    /// the cursor is on the root, which has no siblings and can't be removed
+    AtRoot,
+    /// the tree rejected the new value
+    Rejected(AddError),
+}
+
+impl Display for CursorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::NoSuchNode => f.write_str("there is no node in that direction"),
+            Self::NotFound => f.write_str("no node has the value"),
+            Self::AtRoot => f.write_str("the cursor is on the root"),
+            Self::Rejected(error) => write!(f, "the value was rejected: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CursorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Rejected(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// the reason why [`Node::from_edges()`](crate::Node::from_edges) couldn't assemble a tree
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EdgeError<T> {
+    /// there were no edges
+    Empty,
+    /// the value is the child of more than one edge
+    MultipleParents(T),
+    /// more than one value is never a child, in order of their first edge
+    MultipleRoots(std::vec::Vec<T>),
+    /// every value is a child, so the edges only form cycles
+    NoRoot,
+    /// the values can't be reached from the root because they form a cycle, in order of their first edge
+    Orphaned(std::vec::Vec<T>),
+}
+
+#[cfg(feature = "std")]
+impl<T> Display for EdgeError<T> where T: core::fmt::Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Empty => f.write_str("there are no edges"),
+            Self::MultipleParents(child) => write!(f, "{child:?} has more than one parent"),
+            Self::MultipleRoots(roots) => write!(f, "the edges have more than one root: {roots:?}"),
+            Self::NoRoot => f.write_str("the edges have no root"),
+            Self::Orphaned(values) => write!(f, "{values:?} can't be reached from the root"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for EdgeError<T> where T: core::fmt::Debug {}
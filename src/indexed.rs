@@ -0,0 +1,178 @@
+use alloc::rc::{Rc, Weak};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+use crate::base::RawNode;
+use crate::Node;
+
+/// an owner for a tree with unique values that maps every value to its [Node]
+///
+/// [`IndexedTree::find()`] and the uniqueness check of [`IndexedTree::insert()`] are hash lookups
+/// instead of walks over the tree. Changes made directly through the [Node] handles bypass the map,
+/// [`IndexedTree::reindex()`] builds it again
+#[derive(Debug)]
+pub struct IndexedTree<T> where T: Eq + Hash + Clone {
+    root: Node<T>,
+    index: HashMap<T, Weak<RawNode<T>>>,
+}
+
+impl<T> IndexedTree<T> where T: Eq + Hash + Clone {
+    /// creates a new [`IndexedTree`] with only a root
+    /// ## Example
+    /// ```
+    /// use a_ntree::IndexedTree;
+    /// let tree = IndexedTree::new(10);
+    ///
+    /// assert_eq!(tree.root().value(), 10);
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        let root = Node::new(value);
+        let index = HashMap::from([(root.value(), Rc::downgrade(&root.pointer))]);
+        Self { root, index }
+    }
+
+    /// creates an [`IndexedTree`] owning `root` and its subtree, `root` is detached from its parent first
+    ///
+    /// returns [None] if a value appears more than once in the subtree
+    /// ## Example
+    /// ```
+    /// use a_ntree::{IndexedTree, Node};
+    /// let root = Node::new(10);
+    /// root.add_leaf(20);
+    ///
+    /// let tree = IndexedTree::from_root(root).unwrap();
+    /// assert!(tree.find(&20).is_some());
+    /// ```
+    #[must_use]
+    pub fn from_root(root: Node<T>) -> Option<Self> {
+        root.pointer.detach();
+        let mut tree = Self { root, index: HashMap::new() };
+        tree.reindex().then_some(tree)
+    }
+
+    /// builds the map again from the whole tree, after changes made directly through the [Node] handles
+    ///
+    /// returns false if a value appears more than once, only its first Node in pre-order is mapped then
+    pub fn reindex(&mut self) -> bool {
+        self.index.clear();
+        let mut unique = true;
+        self.root.pointer.visit(&mut |node| {
+            match self.index.entry(node.value().clone()) {
+                Entry::Occupied(_) => unique = false,
+                Entry::Vacant(entry) => {
+                    entry.insert(Rc::downgrade(node));
+                }
+            }
+        });
+        unique
+    }
+
+    /// returns the root of the [`IndexedTree`]
+    #[must_use]
+    pub const fn root(&self) -> &Node<T> {
+        &self.root
+    }
+
+    /// returns the number of nodes in the [`IndexedTree`] without walking it
+    ///
+    /// a tree always contains its root, so there is no `is_empty`
+    #[allow(clippy::len_without_is_empty)]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// returns the [Node] with a value in average constant time
+    /// ## Example
+    /// ```
+    /// use a_ntree::IndexedTree;
+    /// let mut tree = IndexedTree::new("root");
+    /// let root = tree.root().get_root();
+    /// tree.insert_leaf(&root, "child");
+    ///
+    /// assert_eq!(tree.find(&"child").unwrap().parent().unwrap(), root);
+    /// assert!(tree.find(&"other").is_none());
+    /// ```
+    #[must_use]
+    pub fn find(&self, value: &T) -> Option<Node<T>> {
+        let node = self.index.get(value)?.upgrade()?;
+        // a value changed through a handle no longer matches its entry
+        let matches = *node.value() == *value;
+        matches.then(|| Node::from(&node))
+    }
+
+    /// returns true if a [Node] of the [`IndexedTree`] has the value
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        self.find(value).is_some()
+    }
+
+    /// adds `child` and its subtree below `parent`, which has to be part of this [`IndexedTree`]
+    ///
+    /// returns false if `child` has a parent, `parent` belongs to another tree, or a value of the subtree
+    /// is already in the tree or appears twice in the subtree. The check only costs time in the size of the subtree
+    /// ## Example
+    /// ```
+    /// use a_ntree::{IndexedTree, Node};
+    /// let mut tree = IndexedTree::new(0);
+    /// let root = tree.root().get_root();
+    /// let child = Node::new(1);
+    /// child.add_leaf(2);
+    ///
+    /// assert!(tree.insert(&root, &child));
+    /// assert!(!tree.insert(&child, &Node::new(2)));
+    /// assert_eq!(tree.len(), 3);
+    /// ```
+    pub fn insert(&mut self, parent: &Node<T>, child: &Node<T>) -> bool {
+        if !child.is_root() || parent.get_root() != self.root {
+            return false;
+        }
+
+        let mut added = HashMap::new();
+        let mut unique = true;
+        child.pointer.visit(&mut |node| {
+            let value = node.value().clone();
+            unique &= !self.index.contains_key(&value);
+            unique &= added.insert(value, Rc::downgrade(node)).is_none();
+        });
+        if !unique {
+            return false;
+        }
+
+        let linked = parent.add_child_unchecked(child);
+        self.index.extend(added);
+        linked
+    }
+
+    /// adds a value directly as a child of `parent`, see [`IndexedTree::insert()`]
+    pub fn insert_leaf(&mut self, parent: &Node<T>, leaf: T) -> bool {
+        self.insert(parent, &Node::new(leaf))
+    }
+
+    /// removes the [Node] with a value together with its children and returns it
+    ///
+    /// returns [None] for the value of the root or a value that isn't in the tree
+    /// ## Example
+    /// ```
+    /// use a_ntree::IndexedTree;
+    /// let mut tree = IndexedTree::new(0);
+    /// let root = tree.root().get_root();
+    /// tree.insert_leaf(&root, 1);
+    ///
+    /// assert_eq!(tree.remove(&1).unwrap().value(), 1);
+    /// assert!(tree.remove(&0).is_none());
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn remove(&mut self, value: &T) -> Option<Node<T>> {
+        let node = self.find(value)?;
+        if !self.root.remove(&node) {
+            return None;
+        }
+        node.pointer.visit(&mut |removed| {
+            self.index.remove(&*removed.value());
+        });
+        Some(node)
+    }
+}
@@ -0,0 +1,146 @@
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::base::RawNode;
+use crate::Node;
+
+/// the order in which [`Node::walk()`] visits a subtree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TraversalOrder {
+    /// parents before their children, depth first
+    PreOrder,
+    /// children before their parents, depth first
+    PostOrder,
+    /// level by level, left to right
+    BreadthFirst,
+}
+
+/// tells [`Node::visit()`] how to go on after a node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WalkControl {
+    /// descend into the children of the node, then carry on
+    Continue,
+    /// leave out the descendants of the node, then carry on
+    SkipSubtree,
+    /// end the walk
+    Stop,
+}
+
+/// a lazy iterator over a subtree, created by [`Node::walk()`]
+///
+/// only the nodes waiting to be visited are held, children are looked up when their parent is reached
+pub struct Walk<T> {
+    pending: Pending<T>,
+}
+
+enum Pending<T> {
+    PreOrder(Vec<Rc<RawNode<T>>>),
+    /// every node is paired with the index of its next child to descend into
+    PostOrder(Vec<(Rc<RawNode<T>>, usize)>),
+    BreadthFirst(VecDeque<Rc<RawNode<T>>>),
+}
+
+impl<T> Walk<T> {
+    pub(crate) fn new(start: &Rc<RawNode<T>>, order: TraversalOrder) -> Self {
+        let start = Rc::clone(start);
+        let pending = match order {
+            TraversalOrder::PreOrder => Pending::PreOrder(vec![start]),
+            TraversalOrder::PostOrder => Pending::PostOrder(vec![(start, 0)]),
+            TraversalOrder::BreadthFirst => Pending::BreadthFirst(VecDeque::from([start])),
+        };
+        Self { pending }
+    }
+
+    /// a pre-order walk that starts below `start`, leaving it out
+    pub(crate) fn descendants(start: &Rc<RawNode<T>>) -> Self {
+        let children = start.children().borrow().iter().rev().cloned().collect();
+        Self { pending: Pending::PreOrder(children) }
+    }
+}
+
+impl<T> Iterator for Walk<T> {
+    type Item = Node<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.pending {
+            Pending::PreOrder(stack) => {
+                let node = stack.pop()?;
+                stack.extend(node.children().borrow().iter().rev().cloned());
+                Some(Node::from(&node))
+            }
+            Pending::PostOrder(stack) => loop {
+                let (node, next_child) = stack.last_mut()?;
+                let child = node.children().borrow().get(*next_child).cloned();
+                if let Some(child) = child {
+                    *next_child += 1;
+                    stack.push((child, 0));
+                } else {
+                    let (node, _) = stack.pop()?;
+                    return Some(Node::from(&node));
+                }
+            },
+            Pending::BreadthFirst(queue) => {
+                let node = queue.pop_front()?;
+                queue.extend(node.children().borrow().iter().cloned());
+                Some(Node::from(&node))
+            }
+        }
+    }
+
+    /// every pending node is still going to be yielded, their descendants are unknown until reached
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = match &self.pending {
+            Pending::PreOrder(stack) => stack.len(),
+            Pending::PostOrder(stack) => stack.len(),
+            Pending::BreadthFirst(queue) => queue.len(),
+        };
+        (pending, None)
+    }
+}
+
+/// a lazy iterator from the parent of a node up to the root, created by [`Node::ancestors()`]
+pub struct Ancestors<T> {
+    next: Option<Rc<RawNode<T>>>,
+}
+
+impl<T> Ancestors<T> {
+    pub(crate) fn new(start: &Rc<RawNode<T>>) -> Self {
+        Self { next: start.parent() }
+    }
+}
+
+impl<T> Iterator for Ancestors<T> {
+    type Item = Node<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next.take()?;
+        self.next = node.parent();
+        Some(Node::from(&node))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(self.next.is_some()), None)
+    }
+}
+
+/// walks the subtree in pre-order, like [`Node::iter()`]
+/// ## Example
+/// ```
+/// use a_ntree::tree;
+/// let root = tree! { 1 => [2 => [3], 4] };
+/// let mut values = vec![];
+/// for node in &root {
+///     values.push(node.value());
+/// }
+///
+/// assert_eq!(values, vec![1, 2, 3, 4]);
+/// ```
+impl<T> IntoIterator for &Node<T> {
+    type Item = Node<T>;
+    type IntoIter = Walk<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Walk::new(&self.pointer, TraversalOrder::PreOrder)
+    }
+}
@@ -0,0 +1,270 @@
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use core::fmt::{Display, Write};
+#[cfg(feature = "json")]
+use core::str::FromStr;
+use crate::base::RawNode;
+#[cfg(feature = "json")]
+use crate::JsonError;
+use crate::Node;
+
+impl<T> Node<T> where T: Display {
+    /// renders the subtree of a [Node] as nested JSON objects
+    ///
+    /// every node becomes `{"value":"..","children":[..]}`, the value is the [Display] text of `T`
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// root.add_leaf(20);
+    ///
+    /// assert_eq!(root.to_json_string(), r#"{"value":"10","children":[{"value":"20","children":[]}]}"#);
+    ///
+    /// let quoted = Node::new(r#"say "hi""#);
+    /// assert_eq!(quoted.to_json_string(), r#"{"value":"say \"hi\"","children":[]}"#);
+    /// ```
+    #[must_use]
+    pub fn to_json_string(&self) -> String {
+        let mut json = String::new();
+        write_node(&self.pointer, &mut json);
+        json
+    }
+}
+
+/// writes the subtree with an explicit stack, every entry remembers the next child to write
+fn write_node<T>(node: &Rc<RawNode<T>>, json: &mut String) where T: Display {
+    open_node(node, json);
+    let mut stack = vec![(Rc::clone(node), 0)];
+    while let Some((node, next_child)) = stack.last_mut() {
+        let child = node.children().borrow().get(*next_child).cloned();
+        if let Some(child) = child {
+            if *next_child > 0 {
+                json.push(',');
+            }
+            *next_child += 1;
+            open_node(&child, json);
+            stack.push((child, 0));
+        } else {
+            json.push_str("]}");
+            stack.pop();
+        }
+    }
+}
+
+fn open_node<T>(node: &RawNode<T>, json: &mut String) where T: Display {
+    json.push_str(r#"{"value":""#);
+    write_escaped(&node.value().to_string(), json);
+    json.push_str(r#"","children":["#);
+}
+
+fn write_escaped(text: &str, json: &mut String) {
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T> Node<T> where T: FromStr + PartialEq {
+    /// reads a tree in the format of [`Node::to_json_string()`]
+    ///
+    /// the value of a node is a JSON string or a bare literal like a number, both are parsed with [`FromStr`].
+    /// `"value"` has to come before `"children"`, which can be left out for leaves
+    /// ## Example
+    /// ```
+    /// use a_ntree::{JsonError, Node};
+    /// let root: Node<i32> = Node::from_json_str(r#"{"value": 10, "children": [{"value": "20"}]}"#).unwrap();
+    ///
+    /// assert_eq!(root.children_values(), vec![20]);
+    /// assert!(Node::from_json_str(&root.to_json_string()).unwrap().structural_eq(&root));
+    /// assert_eq!(Node::<i32>::from_json_str(r#"{"value": "ten"}"#), Err(JsonError::InvalidValue { position: 10 }));
+    /// ```
+    /// ## Errors
+    /// returns the reason and where in the input reading stopped
+    pub fn from_json_str(json: &str) -> Result<Self, JsonError> {
+        let mut parser = Parser { text: json, position: 0 };
+        let (root, has_children) = parser.node()?;
+        // open nodes whose children are being read, with the number of children read so far
+        let mut stack = vec![];
+        if has_children {
+            stack.push((root.get_root(), 0));
+        }
+        while let Some((parent, read)) = stack.last_mut() {
+            parser.skip_whitespace();
+            if parser.peek() == Some(b']') {
+                parser.position += 1;
+                parser.expect(b'}')?;
+                stack.pop();
+                continue;
+            }
+            if *read > 0 {
+                parser.expect(b',')?;
+                parser.skip_whitespace();
+            }
+            *read += 1;
+
+            let position = parser.position;
+            let (child, has_children) = parser.node()?;
+            parent.try_add_child(&child).map_err(|error| JsonError::Rejected { position, error })?;
+            if has_children {
+                stack.push((child, 0));
+            }
+        }
+
+        parser.skip_whitespace();
+        if parser.position < json.len() {
+            return Err(parser.syntax());
+        }
+        Ok(root)
+    }
+}
+
+#[cfg(feature = "json")]
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+#[cfg(feature = "json")]
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    const fn syntax(&self) -> JsonError {
+        JsonError::Syntax { position: self.position }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), JsonError> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(self.syntax());
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn key(&mut self, key: &str) -> Result<(), JsonError> {
+        self.skip_whitespace();
+        let position = self.position;
+        if self.string()? != key {
+            return Err(JsonError::Syntax { position });
+        }
+        self.expect(b':')
+    }
+
+    /// reads the start of a node up to its value, returns true if a list of children follows
+    fn node<T>(&mut self) -> Result<(Node<T>, bool), JsonError> where T: FromStr {
+        self.expect(b'{')?;
+        self.key("value")?;
+        self.skip_whitespace();
+        let position = self.position;
+        let value = self.scalar()?.parse().map_err(|_| JsonError::InvalidValue { position })?;
+        let node = Node::new(value);
+
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'}') => {
+                self.position += 1;
+                Ok((node, false))
+            }
+            Some(b',') => {
+                self.position += 1;
+                self.key("children")?;
+                self.expect(b'[')?;
+                Ok((node, true))
+            }
+            _ => Err(self.syntax()),
+        }
+    }
+
+    /// reads a string or the text of a bare literal
+    fn scalar(&mut self) -> Result<String, JsonError> {
+        if self.peek() == Some(b'"') {
+            return self.string();
+        }
+        let rest = &self.text[self.position..];
+        let len = rest.find([',', '}', ']', ' ', '\t', '\n', '\r']).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.syntax());
+        }
+        self.position += len;
+        Ok(rest[..len].to_string())
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        if self.peek() != Some(b'"') {
+            return Err(self.syntax());
+        }
+        self.position += 1;
+        let mut text = String::new();
+        loop {
+            let position = self.position;
+            let c = self.next_char()?;
+            match c {
+                '"' => return Ok(text),
+                '\\' => match self.next_char()? {
+                    c @ ('"' | '\\' | '/') => text.push(c),
+                    'b' => text.push('\u{8}'),
+                    'f' => text.push('\u{c}'),
+                    'n' => text.push('\n'),
+                    'r' => text.push('\r'),
+                    't' => text.push('\t'),
+                    'u' => text.push(self.unicode(position)?),
+                    _ => return Err(JsonError::Syntax { position }),
+                },
+                c if c < ' ' => return Err(JsonError::Syntax { position }),
+                c => text.push(c),
+            }
+        }
+    }
+
+    fn next_char(&mut self) -> Result<char, JsonError> {
+        let c = self.text[self.position..].chars().next().ok_or_else(|| self.syntax())?;
+        self.position += c.len_utf8();
+        Ok(c)
+    }
+
+    /// reads the digits of a `\u` escape that starts at `position`, including the second half of a surrogate pair
+    fn unicode(&mut self, position: usize) -> Result<char, JsonError> {
+        let high = self.hex()?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            if !self.text[self.position..].starts_with("\\u") {
+                return Err(JsonError::Syntax { position });
+            }
+            self.position += 2;
+            let low = self.hex()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(JsonError::Syntax { position });
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or(JsonError::Syntax { position })
+    }
+
+    fn hex(&mut self) -> Result<u32, JsonError> {
+        let digits = self.text.get(self.position..self.position + 4)
+            .filter(|digits| digits.bytes().all(|byte| byte.is_ascii_hexdigit()))
+            .ok_or_else(|| self.syntax())?;
+        self.position += 4;
+        u32::from_str_radix(digits, 16).map_err(|_| self.syntax())
+    }
+}
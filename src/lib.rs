@@ -2,27 +2,105 @@
 #![warn(clippy::pedantic)]
 #![warn(clippy::nursery)]
 #![warn(clippy::cargo)]
+#![no_std]
 
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+pub mod arena;
 mod base;
-use std::rc::Rc;
-use std::fmt::Debug;
+mod cursor;
+#[cfg(feature = "std")]
+mod diff;
+mod display;
+#[cfg(feature = "dot")]
+mod dot;
+#[cfg(feature = "std")]
+mod edges;
+mod edit;
+mod error;
+#[cfg(feature = "std")]
+mod indexed;
+mod iter;
+mod json;
+#[cfg(feature = "std")]
+mod merge;
+mod nested;
+mod policy;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "std")]
+pub mod sync;
+mod tree;
+mod weak;
+use alloc::rc::Rc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::Ref;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 use crate::base::RawNode;
 
+#[cfg(feature = "std")]
+pub use crate::diff::TreeDiff;
+pub use crate::display::RenderStyle;
+pub use crate::edit::{diff, Edit};
+pub use crate::cursor::Cursor;
+pub use crate::error::{AddError, CursorError, InvariantError};
+#[cfg(feature = "std")]
+pub use crate::error::EdgeError;
+#[cfg(feature = "json")]
+pub use crate::error::JsonError;
+#[cfg(feature = "std")]
+pub use crate::indexed::IndexedTree;
+pub use crate::iter::{Ancestors, TraversalOrder, Walk, WalkControl};
+#[cfg(feature = "std")]
+pub use crate::merge::MergeStrategy;
+pub use crate::nested::NestedNode;
+pub use crate::policy::TreePolicy;
+#[cfg(feature = "std")]
+pub use crate::sync::SyncNode;
+pub use crate::tree::{SubscriptionId, Tree, TreeEvent};
+pub use crate::weak::WeakNode;
+
 #[derive(Debug)]
 /// a singular Node that holds a generic value
-pub struct Node<T> where T: PartialEq {
+pub struct Node<T> {
     pointer: Rc<RawNode<T>>,
 }
 
-impl<T> PartialEq for Node<T> where T: PartialEq {
+impl<T> PartialEq for Node<T> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         Rc::ptr_eq(&self.pointer, &other.pointer)
     }
 }
 
+/// appends every value as a leaf, values the tree rejects are skipped like with [`Node::add_leaf()`]
+/// ## Example
+/// ```
+/// use a_ntree::Node;
+/// let mut root = Node::new(0);
+/// root.extend([1, 2, 0, 3]);
+///
+/// assert_eq!(root.children_values(), vec![1, 2, 3]);
+/// ```
+impl<T> Extend<T> for Node<T> where T: PartialEq {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.add_leaf(value);
+        }
+    }
+}
+
 #[allow(unused)]
-impl<T> Node<T> where T: PartialEq {
+impl<T> Node<T> {
     /// creates a new [Node] with a value
     /// ## Example
     /// ```
@@ -36,21 +114,170 @@ impl<T> Node<T> where T: PartialEq {
         Self { pointer: Rc::new(RawNode::new(value)) }
     }
 
+    /// replaces the value of a [Node] and returns the old one
+    ///
+    /// the new value is not checked against the rest of the tree,
+    /// see [`Node::try_set_value()`] to keep the values unique
+    /// ## Panics
+    /// if the value is currently borrowed through [`Node::borrow_value()`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let node = Node::new(10);
+    ///
+    /// assert_eq!(node.set_value(20), 10);
+    /// assert_eq!(node.value(), 20);
+    /// ```
+    pub fn set_value(&self, new: T) -> T {
+        core::mem::replace(&mut self.pointer.value_mut(), new)
+    }
+
+    /// changes the value of a [Node] in place
+    ///
+    /// the same rules as for [`Node::set_value()`] apply, see [`Node::update()`] for a checked change
+    /// ## Panics
+    /// if the value is currently borrowed through [`Node::borrow_value()`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let node = Node::new(String::from("a"));
+    /// node.with_value_mut(|value| value.push('b'));
+    ///
+    /// assert_eq!(node.value(), "ab");
+    /// ```
+    pub fn with_value_mut<F: FnOnce(&mut T)>(&self, f: F) {
+        f(&mut self.pointer.value_mut());
+    }
+
+    /// copies the subtree of a [Node] into a new, independent tree
+    ///
+    /// every node is allocated anew, the copy has no parent and takes over the [`TreePolicy`] of the original tree
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// child.add_leaf(30);
+    ///
+    /// let copy = child.deep_clone();
+    /// assert!(copy.is_root());
+    /// assert_ne!(copy, child);
+    /// assert_eq!(copy.children_values(), vec![30]);
+    /// ```
+    #[must_use]
+    pub fn deep_clone(&self) -> Self where T: Clone {
+        self.map(T::clone)
+    }
+
+    /// creates a new [Node] with a value as the root of a tree with the given [`TreePolicy`]
+    ///
+    /// the policy belongs to the root: it is ignored while this Node is part of another tree
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, TreePolicy};
+    /// let root = Node::with_policy("src", TreePolicy::AllowDuplicates);
+    /// let lib = Node::new("lib");
+    /// root.add_child(&lib);
+    ///
+    /// assert!(lib.add_leaf("src"));
+    /// assert_eq!(root.find(&"src").unwrap(), root);
+    /// assert_eq!(lib.policy(), TreePolicy::AllowDuplicates);
+    /// ```
+    #[must_use]
+    pub fn with_policy(value: T, policy: TreePolicy) -> Self {
+        let node = Self::new(value);
+        node.pointer.set_policy(policy);
+        node
+    }
+
+    /// returns the [`TreePolicy`] of the tree this [Node] belongs to
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, TreePolicy};
+    /// let root = Node::new(10);
+    ///
+    /// assert_eq!(root.policy(), TreePolicy::UniqueValues);
+    /// ```
+    #[must_use]
+    pub fn policy(&self) -> TreePolicy {
+        self.pointer.get_root().policy()
+    }
+
+    /// opts the tree rooted at this [Node] out of the uniqueness check, so values may repeat
+    ///
+    /// shorthand for [`TreePolicy::AllowDuplicates`], see [`Node::with_policy()`].
+    /// Nodes still can't be added twice or below themselves.
+    /// [`Node::find()`] and [`Node::remove_node()`] resolve to the first match in pre-order,
+    /// [`Node::find_all()`] returns every match
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new("tag").allow_duplicates();
+    /// let first = Node::new("tag");
+    ///
+    /// assert!(root.add_child(&first));
+    /// assert!(first.add_leaf("tag"));
+    /// assert!(!root.add_child(&first));
+    /// assert_eq!(root.find(&"tag").unwrap(), root);
+    /// ```
+    #[must_use]
+    pub fn allow_duplicates(self) -> Self {
+        self.pointer.set_policy(TreePolicy::AllowDuplicates);
+        self
+    }
+
+    /// returns true if the tree this [Node] belongs to allows duplicate values
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10).allow_duplicates();
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    ///
+    /// assert!(child.allows_duplicates());
+    /// assert!(!Node::new(30).allows_duplicates());
+    /// ```
+    #[must_use]
+    pub fn allows_duplicates(&self) -> bool {
+        self.policy() == TreePolicy::AllowDuplicates
+    }
+
     /// internal method to get a [`RawNode`] as a [`Node`]
     fn from(pointer: &Rc<RawNode<T>>) -> Self {
         Self { pointer: Rc::clone(pointer) }
     }
 
-    /// returns the value of a [Node] by reference
+    /// returns a copy of the value of a [Node]
+    ///
+    /// use [`Node::borrow_value()`] to read the value without cloning it
     /// ## Example
     /// ```
     /// use a_ntree::Node;
     /// let node = Node::new(10);
     ///
-    /// assert_eq!(node.value(), &10)
+    /// assert_eq!(node.value(), 10)
+    /// ```
+    #[must_use]
+    pub fn value(&self) -> T where T: Clone {
+        self.pointer.value().clone()
+    }
+
+    /// borrows the value of a [Node] without cloning it
+    ///
+    /// the value lives in a [`RefCell`](core::cell::RefCell), the returned guard keeps it borrowed until it is dropped.
+    /// Any number of guards can be held at once, but holding one across [`Node::set_value()`] or
+    /// [`Node::with_value_mut()`] panics
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let node = Node::new(String::from("label"));
+    ///
+    /// assert_eq!(node.borrow_value().len(), 5);
+    /// assert_eq!(*node.borrow_value(), "label");
     /// ```
     #[must_use]
-    pub fn value(&self) -> &T {
+    pub fn borrow_value(&self) -> Ref<'_, T> {
         self.pointer.value()
     }
 
@@ -75,6 +302,142 @@ impl<T> Node<T> where T: PartialEq {
         None
     }
 
+    /// returns a lazy iterator over the parent, grandparent and so on up to the root
+    ///
+    /// the calling [Node] is not included, so the iterator of a root is empty
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// child.add_leaf(30);
+    ///
+    /// let values: Vec<i32> = root.find(&30).unwrap().ancestors().map(|node| node.value()).collect();
+    /// assert_eq!(values, vec![20, 10]);
+    /// assert_eq!(root.ancestors().count(), 0);
+    /// ```
+    #[must_use]
+    pub fn ancestors(&self) -> Ancestors<T> {
+        Ancestors::new(&self.pointer)
+    }
+
+    /// returns the nodes from the root down to this [Node], both inclusive
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// child.add_leaf(30);
+    ///
+    /// let values: Vec<i32> = root.find(&30).unwrap().path_from_root().iter().map(Node::value).collect();
+    /// assert_eq!(values, vec![10, 20, 30]);
+    /// ```
+    #[must_use]
+    pub fn path_from_root(&self) -> Vec<Self> {
+        let mut path: Vec<Self> = self.ancestors().collect();
+        path.reverse();
+        path.push(Self::from(&self.pointer));
+        path
+    }
+
+    /// returns the values from the root down to this [Node], both inclusive
+    ///
+    /// the values live behind a [`RefCell`](core::cell::RefCell), so they are returned as copies
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new("usr");
+    /// let child = Node::new("lib");
+    /// root.add_child(&child);
+    ///
+    /// assert_eq!(child.path(), vec!["usr", "lib"]);
+    /// ```
+    #[must_use]
+    pub fn path(&self) -> Vec<T> where T: Clone {
+        self.path_from_root().iter().map(Self::value).collect()
+    }
+
+    /// returns the child indices leading from the root down to this [Node], empty for the root
+    ///
+    /// resolve it again with [`Node::node_at_index_path()`] on the root.
+    /// The path only stays valid as long as the children on the way are not reordered
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// root.add_leaf(1);
+    /// let child = Node::new(2);
+    /// root.add_child(&child);
+    /// child.add_leaf(3);
+    ///
+    /// let leaf = root.find(&3).unwrap();
+    /// assert_eq!(leaf.index_path(), vec![1, 0]);
+    /// assert_eq!(root.node_at_index_path(&leaf.index_path()).unwrap(), leaf);
+    /// ```
+    #[must_use]
+    pub fn index_path(&self) -> Vec<usize> {
+        let mut path = vec![];
+        let mut current = Rc::clone(&self.pointer);
+        while let Some((parent, idx)) = current.position() {
+            path.push(idx);
+            current = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// follows `path` down from this [Node], taking the child at each index in turn
+    ///
+    /// an empty path resolves to the Node itself, [None] if an index is out of range
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// root.add_leaf(1);
+    ///
+    /// assert_eq!(root.node_at_index_path(&[0]).unwrap().value(), 1);
+    /// assert_eq!(root.node_at_index_path(&[]).unwrap(), root);
+    /// assert!(root.node_at_index_path(&[0, 0]).is_none());
+    /// ```
+    #[must_use]
+    pub fn node_at_index_path(&self, path: &[usize]) -> Option<Self> {
+        path.iter().try_fold(Self::from(&self.pointer), |node, idx| node.child_at(*idx))
+    }
+
+    /// returns true if the [Node] has no parent
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    ///
+    /// assert!(root.is_root());
+    /// assert!(!child.is_root());
+    /// ```
+    #[must_use]
+    pub fn is_root(&self) -> bool {
+        self.pointer.parent().is_none()
+    }
+
+    /// returns true if the [Node] has no children
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    ///
+    /// assert!(child.is_leaf());
+    /// assert!(!root.is_leaf());
+    /// ```
+    #[must_use]
+    pub fn is_leaf(&self) -> bool {
+        self.pointer.children().borrow().is_empty()
+    }
+
     /// returns the children of a [Node]
     /// ## Example
     /// ```
@@ -96,107 +459,1557 @@ impl<T> Node<T> where T: PartialEq {
         ret
     }
 
-    /// adds a child to a [Node] if the child or any of its children are not in the tree
+    /// returns the other children of the parent of a [Node], in order
     ///
-    /// returns true if it added, else false
+    /// the root has no siblings
     /// ## Example
     /// ```
     /// use a_ntree::Node;
     /// let root = Node::new(10);
     /// let child = Node::new(20);
-    /// let should_be_true = root.add_child(&child);
-    /// let should_be_false = root.add_child(&child);
+    /// root.add_child(&child);
+    /// root.add_leaf(30);
     ///
-    /// assert_eq!(should_be_true, true);
-    /// assert_eq!(should_be_false, false);
+    /// assert_eq!(child.siblings().len(), 1);
+    /// assert_eq!(child.siblings()[0].value(), 30);
+    /// assert!(root.siblings().is_empty());
     /// ```
     #[must_use]
-    pub fn add_child(&self, child: &Self) -> bool {
-        self.pointer.add_child(&child.pointer)
+    pub fn siblings(&self) -> Vec<Self> {
+        self.parent().map_or_else(Vec::new, |parent| {
+            parent.children().into_iter().filter(|child| child != self).collect()
+        })
     }
 
-    /// adds a value directly as a child to a [`Node`]
-    ///
-    /// same as [`Node::add_child()`] but without the need to create a new Node
+    /// returns the child of a [Node] at `index`, or [None] if there are not that many children
     /// ## Example
     /// ```
     /// use a_ntree::Node;
     /// let root = Node::new(10);
-    /// root.add_leaf(30);
+    /// root.add_leaf(20);
     ///
-    /// assert_eq!(root.children().get(0).unwrap().value(), &30);
+    /// assert_eq!(root.child_at(0).unwrap().value(), 20);
+    /// assert!(root.child_at(1).is_none());
     /// ```
-    pub fn add_leaf(&self, leaf: T) -> bool {
-        self.add_child(&Self::new(leaf))
+    #[must_use]
+    pub fn child_at(&self, index: usize) -> Option<Self> {
+        self.pointer.children().borrow().get(index).map(Self::from)
     }
 
-    /// searches a [Node] by value - starting from the calling Node inclusive
-    ///
-    /// returns the first Node found or [None] if the value doesnt exist
+    /// returns the position of this [Node] among the children of its parent, or [None] for the root
     /// ## Example
     /// ```
     /// use a_ntree::Node;
     /// let root = Node::new(10);
     /// root.add_leaf(20);
-    /// root.add_leaf(30);
+    /// let child = Node::new(30);
+    /// root.add_child(&child);
     ///
-    /// assert_eq!(root.find(&30).unwrap().value(), &30);
-    /// assert_eq!(root.find(&20).unwrap().value(), &20);
-    /// assert_eq!(root.find(&10).unwrap().value(), &10);
-    /// assert!(root.find(&999999).is_none());
-    ///```
-    pub fn find(&self, value: &T) -> Option<Self> {
-        if let Some(found) = self.pointer.find(value) {
-            return Some(Self::from(&found));
-        }
-        None
+    /// assert_eq!(child.child_index(), Some(1));
+    /// assert_eq!(root.child_index(), None);
+    /// ```
+    #[must_use]
+    pub fn child_index(&self) -> Option<usize> {
+        self.pointer.position().map(|(_, idx)| idx)
     }
 
-    /// removes the first child [Node] from this Node and all children
+    /// returns the child of the parent that follows this [Node], or [None] for the last child and the root
     /// ## Example
     /// ```
     /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let first = Node::new(20);
+    /// let second = Node::new(30);
+    /// root.add_child(&first);
+    /// root.add_child(&second);
     ///
+    /// assert_eq!(first.next_sibling().unwrap(), second);
+    /// assert!(second.next_sibling().is_none());
+    /// ```
+    #[must_use]
+    pub fn next_sibling(&self) -> Option<Self> {
+        let (parent, idx) = self.pointer.position()?;
+        let siblings = parent.children().borrow();
+        siblings.get(idx + 1).map(Self::from)
+    }
+
+    /// returns the child of the parent that precedes this [Node], or [None] for the first child and the root
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
     /// let root = Node::new(10);
-    /// root.add_leaf(30);
-    /// root.add_leaf(40);
-    /// // root has 2 children
-    /// root.remove_node(&40);
-    /// // root has 1 child
-    /// assert_eq!(root.children().len(), 1);
-    /// assert!(root.find(&40).is_none());
+    /// let first = Node::new(20);
+    /// let second = Node::new(30);
+    /// root.add_child(&first);
+    /// root.add_child(&second);
+    ///
+    /// assert_eq!(second.prev_sibling().unwrap(), first);
+    /// assert!(first.prev_sibling().is_none());
     /// ```
-    pub fn remove_node(&self, value: &T) -> Option<Self> {
-        self.pointer.remove_node(value).map(|raw_node| Self::from(&raw_node))
+    #[must_use]
+    pub fn prev_sibling(&self) -> Option<Self> {
+        let (parent, idx) = self.pointer.position()?;
+        let siblings = parent.children().borrow();
+        siblings.get(idx.checked_sub(1)?).map(Self::from)
     }
 
-    /// get the root [Node]
+    /// returns the values of the direct children of a [Node]
     ///
-    /// if this Node has no parents, this Node is the root Node
+    /// cheaper than [`Node::children()`] when only the values are needed
     /// ## Example
     /// ```
     /// use a_ntree::Node;
-    /// let root = Node::new(10);
-    /// let a_child = Node::new(20);
-    /// let child_of_child = Node::new(30);
-    /// root.add_child(&a_child);
-    /// a_child.add_child(&child_of_child);
+    /// let root = Node::new("root");
+    /// root.add_leaf("a");
+    /// root.add_leaf("b");
     ///
-    /// assert_eq!(child_of_child.get_root(), root);
+    /// assert_eq!(root.children_values(), vec!["a", "b"]);
     /// ```
     #[must_use]
-    pub fn get_root(&self) -> Self {
-        Self::from(&self.pointer.get_root())
+    pub fn children_values(&self) -> Vec<T> where T: Clone {
+        self.pointer.children().borrow().iter().map(|child| child.value().clone()).collect()
     }
 
-    /// gets the number of strong pointers of this Node
+    /// adds a child to a [Node] like [`Node::add_child()`], but looks up values in a hash index
+    ///
+    /// the index is kept on the root, so the check only costs time in the size of the child's subtree
+    /// instead of the whole tree. Any other change to the tree drops the index,
+    /// the next indexed insert then builds it once from the whole tree
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// for value in 1..1000 {
+    ///     assert!(root.add_leaf_indexed(value));
+    /// }
+    ///
+    /// assert!(!root.add_child_indexed(&Node::new(500)));
+    /// assert_eq!(root.len(), 1000);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn add_child_indexed(&self, child: &Self) -> bool where T: Hash + Eq {
+        self.pointer.add_child_indexed(&child.pointer).is_ok()
+    }
+
+    /// adds a value directly as a child to a [`Node`] using [`Node::add_child_indexed()`]
+    #[cfg(feature = "std")]
+    pub fn add_leaf_indexed(&self, leaf: T) -> bool where T: Hash + Eq {
+        self.add_child_indexed(&Self::new(leaf))
+    }
+
+    /// adds a child to a [Node] without checking the values of the tree
+    ///
+    /// the tree may contain duplicate values afterwards, value based methods like [`Node::find()`]
+    /// and [`Node::remove_node()`] then resolve to the first match in pre-order.
+    /// A child that already has a parent is moved, only adding this Node or one of its ancestors is rejected
+    ///
+    /// returns true if it added, else false
+    /// ## Example
     /// ```
     /// use a_ntree::Node;
     /// let root = Node::new(10);
-    /// assert_eq!(root.rc_count(), 1);
+    /// let child = Node::new(10);
+    ///
+    /// assert!(root.add_child_unchecked(&child));
+    /// assert!(!child.add_child_unchecked(&root));
+    /// assert_eq!(root.find(&10).unwrap(), root);
     /// ```
     #[must_use]
-    pub fn rc_count(&self) -> usize  {
-     Rc::strong_count(&self.pointer)
+    pub fn add_child_unchecked(&self, child: &Self) -> bool {
+        self.pointer.add_child_unchecked(&child.pointer)
+    }
+
+    /// adds a child to a [Node] at the position that keeps the children sorted by value
+    ///
+    /// the child is placed after children with an equal value, the same checks as [`Node::add_child()`] apply
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// root.add_child_sorted(&Node::new(30));
+    /// root.add_child_sorted(&Node::new(10));
+    /// root.add_child_sorted(&Node::new(20));
+    ///
+    /// assert_eq!(root.children_values(), vec![10, 20, 30]);
+    /// ```
+    #[must_use]
+    pub fn add_child_sorted(&self, child: &Self) -> bool where T: Ord {
+        let idx = self.pointer.children().borrow()
+            .partition_point(|node| *node.value() <= *child.pointer.value());
+        self.pointer.insert_child(idx, &child.pointer).is_ok()
+    }
+
+    /// rotates the children of a [Node] `n` positions to the left, the first children move to the end
+    ///
+    /// `n` wraps around the number of children
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// root.add_leaf(1);
+    /// root.add_leaf(2);
+    /// root.add_leaf(3);
+    /// root.rotate_children_left(1);
+    ///
+    /// assert_eq!(root.children_values(), vec![2, 3, 1]);
+    /// ```
+    pub fn rotate_children_left(&self, n: usize) {
+        let mut children = self.pointer.children().borrow_mut();
+        if !children.is_empty() {
+            let mid = n % children.len();
+            children.rotate_left(mid);
+        }
+    }
+
+    /// rotates the children of a [Node] `n` positions to the right, the last children move to the front
+    ///
+    /// `n` wraps around the number of children
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// root.add_leaf(1);
+    /// root.add_leaf(2);
+    /// root.add_leaf(3);
+    /// root.rotate_children_right(1);
+    ///
+    /// assert_eq!(root.children_values(), vec![3, 1, 2]);
+    /// ```
+    pub fn rotate_children_right(&self, n: usize) {
+        let mut children = self.pointer.children().borrow_mut();
+        if !children.is_empty() {
+            let mid = n % children.len();
+            children.rotate_right(mid);
+        }
+    }
+
+    /// sorts the children of a [Node] by their values with `compare`, children that compare equal keep their order
+    /// ## Panics
+    /// if the value of a child is currently borrowed mutably, for example from within [`Node::with_value_mut()`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let menu = Node::new("menu");
+    /// menu.add_leaf("save");
+    /// menu.add_leaf("open");
+    /// menu.add_leaf("close");
+    /// menu.sort_children_by(|a, b| a.cmp(b));
+    ///
+    /// assert_eq!(menu.children_values(), vec!["close", "open", "save"]);
+    /// ```
+    pub fn sort_children_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) {
+        self.pointer.children().borrow_mut().sort_by(|a, b| compare(&a.value(), &b.value()));
+    }
+
+    /// edits the children of a [Node] in place through a [Vec] of handles
+    ///
+    /// once `f` returns, the Vec becomes the new list of children: removed children become roots,
+    /// added nodes leave their old parent and repeated handles are only kept once.
+    /// Values are **not** re-validated, keeping the tree free of duplicates is up to the caller
+    /// ## Panics
+    /// if the edited list contains this Node or one of its ancestors
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// root.add_leaf(1);
+    /// root.add_leaf(2);
+    /// root.add_leaf(3);
+    /// let removed = root.edit_children(|children| {
+    ///     children.reverse();
+    ///     children.remove(1)
+    /// });
+    ///
+    /// assert_eq!(root.children_values(), vec![3, 1]);
+    /// assert!(removed.is_root());
+    /// ```
+    pub fn edit_children<R, F: FnOnce(&mut Vec<Self>) -> R>(&self, f: F) -> R {
+        let mut children = self.children();
+        let result = f(&mut children);
+
+        assert!(
+            !children.iter().any(|child| child.subtree_contains_node(self)),
+            "a node can't become a child of itself or its descendants"
+        );
+        self.pointer.replace_children(children.into_iter().map(|child| child.pointer).collect());
+        result
+    }
+
+    /// removes all children from a [Node] and returns them as independent roots
+    ///
+    /// the children keep their own subtrees, so they can be attached somewhere else
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// root.add_leaf(20);
+    /// root.add_leaf(30);
+    /// let drained = root.drain_children();
+    ///
+    /// assert!(root.children().is_empty());
+    /// assert_eq!(drained.len(), 2);
+    /// assert!(drained[0].is_root());
+    /// ```
+    #[must_use]
+    pub fn drain_children(&self) -> Vec<Self> {
+        self.pointer.drain_children().iter().map(Self::from).collect()
+    }
+
+    /// removes every subtree that has no node satisfying `keep`
+    ///
+    /// what remains are the matching nodes and the nodes on the way to them,
+    /// the calling Node itself is never removed
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(1);
+    /// let child = Node::new(2);
+    /// root.add_child(&child);
+    /// child.add_leaf(3);
+    /// root.add_leaf(4);
+    /// root.retain_paths_to(|value| *value == 3);
+    ///
+    /// assert_eq!(root.children_values(), vec![2]);
+    /// assert_eq!(child.children_values(), vec![3]);
+    /// ```
+    pub fn retain_paths_to<F: Fn(&T) -> bool>(&self, keep: F) {
+        self.pointer.retain_paths_to(&keep);
+    }
+
+    /// removes every descendant whose value fails `keep`, together with its children
+    ///
+    /// the calling Node itself is never removed, see [`Node::retain_paths_to()`] to keep the ancestors of matches instead
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(1);
+    /// let child = Node::new(2);
+    /// root.add_child(&child);
+    /// child.add_leaf(3);
+    /// root.add_leaf(4);
+    /// root.prune(|value| value % 2 == 1);
+    ///
+    /// assert!(root.children().is_empty());
+    /// assert!(child.is_root());
+    /// ```
+    pub fn prune<F: Fn(&T) -> bool>(&self, keep: F) {
+        self.pointer.prune(&keep);
+    }
+
+    /// creates a new tree with the same shape as the subtree of a [Node], passing every value through `f`
+    ///
+    /// the copy has no parent and takes over the [`TreePolicy`] of the original tree.
+    /// The mapped values are not checked, `f` should keep distinct values distinct in a tree with unique values
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(1);
+    /// root.add_leaf(2);
+    ///
+    /// let names = root.map(|value| format!("#{value}"));
+    /// assert_eq!(names.value(), "#1");
+    /// assert_eq!(names.children_values(), vec!["#2"]);
+    /// ```
+    #[must_use]
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Node<U> {
+        let copy = self.pointer.map(&f);
+        copy.set_policy(self.policy());
+        Node { pointer: copy }
+    }
+
+    /// searches a [Node] whose value satisfies `pred` - starting from the calling Node inclusive
+    ///
+    /// returns the first match in pre-order or [None]
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new((1, "root"));
+    /// root.add_leaf((2, "leaf"));
+    ///
+    /// assert_eq!(root.find_by(|(_, name)| *name == "leaf").unwrap().value(), (2, "leaf"));
+    /// assert!(root.find_by(|(id, _)| *id > 2).is_none());
+    /// ```
+    pub fn find_by<F: Fn(&T) -> bool>(&self, pred: F) -> Option<Self> {
+        self.pointer.find_by(&pred).as_ref().map(Self::from)
+    }
+
+    /// searches every [Node] whose value satisfies `pred` - starting from the calling Node inclusive
+    ///
+    /// returns the matches in pre-order
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(1);
+    /// root.add_leaf(2);
+    /// root.add_leaf(3);
+    /// root.add_leaf(4);
+    ///
+    /// let even: Vec<i32> = root.find_all_by(|value| value % 2 == 0).iter().map(Node::value).collect();
+    /// assert_eq!(even, vec![2, 4]);
+    /// ```
+    #[must_use]
+    pub fn find_all_by<F: Fn(&T) -> bool>(&self, pred: F) -> Vec<Self> {
+        let mut found = vec![];
+        self.pointer.find_all_by(&pred, &mut found);
+        found.iter().map(Self::from).collect()
+    }
+
+    /// returns true if `other` is this [Node] or one of its descendants
+    ///
+    /// compares by identity, another Node with an equal value doesn't count
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    ///
+    /// assert!(root.subtree_contains_node(&child));
+    /// assert!(!child.subtree_contains_node(&root));
+    /// assert!(!root.subtree_contains_node(&Node::new(20)));
+    /// ```
+    #[must_use]
+    pub fn subtree_contains_node(&self, other: &Self) -> bool {
+        self.pointer.contains_node(&other.pointer)
+    }
+
+    /// returns true if this [Node] is the parent, grandparent and so on of `other`
+    ///
+    /// compares by identity, a Node is not its own ancestor
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    ///
+    /// assert!(root.is_ancestor_of(&child));
+    /// assert!(!child.is_ancestor_of(&root));
+    /// assert!(!root.is_ancestor_of(&root));
+    /// ```
+    #[must_use]
+    pub fn is_ancestor_of(&self, other: &Self) -> bool {
+        self != other && self.subtree_contains_node(other)
+    }
+
+    /// returns true if `other` is the parent, grandparent and so on of this [Node], see [`Node::is_ancestor_of()`]
+    #[must_use]
+    pub fn is_descendant_of(&self, other: &Self) -> bool {
+        other.is_ancestor_of(self)
+    }
+
+    /// returns the deepest [Node] that has both this Node and `other` in its subtree
+    ///
+    /// a Node is part of its own subtree, so the ancestor of a Node and its child is the Node itself.
+    /// Returns [None] if the two Nodes are in different trees
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let ceo = Node::new("ceo");
+    /// let cto = Node::new("cto");
+    /// ceo.add_child(&cto);
+    /// cto.add_leaf("dev");
+    /// cto.add_leaf("ops");
+    /// let dev = ceo.find(&"dev").unwrap();
+    /// let ops = ceo.find(&"ops").unwrap();
+    ///
+    /// assert_eq!(dev.lowest_common_ancestor(&ops).unwrap(), cto);
+    /// assert_eq!(dev.lowest_common_ancestor(&ceo).unwrap(), ceo);
+    /// assert!(dev.lowest_common_ancestor(&Node::new("intern")).is_none());
+    /// ```
+    #[must_use]
+    pub fn lowest_common_ancestor(&self, other: &Self) -> Option<Self> {
+        self.pointer.lowest_common_ancestor(&other.pointer).as_ref().map(Self::from)
+    }
+
+    /// returns the number of edges on the path between this [Node] and `other`, or [None] if they are in different trees
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(1);
+    /// let child = Node::new(2);
+    /// root.add_child(&child);
+    /// child.add_leaf(3);
+    /// root.add_leaf(4);
+    /// let three = root.find(&3).unwrap();
+    ///
+    /// assert_eq!(three.distance_to(&root.find(&4).unwrap()), Some(3));
+    /// assert_eq!(three.distance_to(&three), Some(0));
+    /// assert_eq!(three.distance_to(&Node::new(5)), None);
+    /// ```
+    #[must_use]
+    pub fn distance_to(&self, other: &Self) -> Option<usize> {
+        let ancestor = self.pointer.lowest_common_ancestor(&other.pointer)?;
+        Some(self.depth() + other.depth() - 2 * ancestor.depth())
+    }
+
+    /// removes every direct child of a [Node] whose value fails `keep`, together with its children
+    ///
+    /// see [`Node::prune()`] to check the whole subtree
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// for value in 1..=4 {
+    ///     root.add_leaf(value);
+    /// }
+    /// root.retain_children(|value| value % 2 == 0);
+    ///
+    /// assert_eq!(root.children_values(), vec![2, 4]);
+    /// ```
+    pub fn retain_children<F: Fn(&T) -> bool>(&self, keep: F) {
+        self.pointer.retain_children(&keep);
+    }
+
+    /// returns the subtree of a [Node] grouped by level, starting with the Node itself
+    ///
+    /// each level is ordered left to right
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// root.add_leaf(30);
+    /// child.add_leaf(21);
+    ///
+    /// let values: Vec<Vec<i32>> = root.levels().iter()
+    ///     .map(|level| level.iter().map(|node| node.value()).collect())
+    ///     .collect();
+    /// assert_eq!(values, vec![vec![10], vec![20, 30], vec![21]]);
+    /// ```
+    #[must_use]
+    pub fn levels(&self) -> Vec<Vec<Self>> {
+        let mut levels = vec![];
+        let mut current = vec![Rc::clone(&self.pointer)];
+
+        while !current.is_empty() {
+            let next = current.iter().flat_map(|node| node.children().borrow().clone()).collect();
+            levels.push(current.iter().map(Self::from).collect());
+            current = next;
+        }
+
+        levels
+    }
+
+    /// returns the number of nodes in the subtree of a [Node], including the Node itself
+    ///
+    /// a subtree always contains at least its own root, so there is no `is_empty`
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// root.add_leaf(20);
+    /// root.add_leaf(30);
+    ///
+    /// assert_eq!(root.len(), 3);
+    /// assert_eq!(root.find(&20).unwrap().len(), 1);
+    /// ```
+    #[must_use]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.pointer.len()
+    }
+
+    /// returns the number of nodes in the subtree of this [Node], the same as [`Node::len()`]
+    #[must_use]
+    pub fn subtree_len(&self) -> usize {
+        self.len()
+    }
+
+    /// returns the number of edges between the root and this [Node], the root has depth 0
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    ///
+    /// assert_eq!(root.depth(), 0);
+    /// assert_eq!(child.depth(), 1);
+    /// ```
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.pointer.depth()
+    }
+
+    /// returns the number of edges on the longest path from this [Node] down to a leaf, a leaf has height 0
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// child.add_leaf(30);
+    ///
+    /// assert_eq!(root.height(), 2);
+    /// assert_eq!(child.height(), 1);
+    /// ```
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.pointer.height()
+    }
+
+    /// returns how many nodes are exactly `depth` levels below this [Node]
+    ///
+    /// depth 0 is the Node itself, deeper levels are not visited
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::balanced_from(&[1, 2, 3, 4, 5], 2).unwrap();
+    ///
+    /// assert_eq!(root.count_at_depth(0), 1);
+    /// assert_eq!(root.count_at_depth(2), 2);
+    /// assert_eq!(root.count_at_depth(3), 0);
+    /// ```
+    #[must_use]
+    pub fn count_at_depth(&self, depth: usize) -> usize {
+        self.pointer.count_at_depth(depth)
+    }
+
+    /// returns every [Node] without children in the subtree, in pre-order
+    ///
+    /// a Node without children is its own only leaf
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// child.add_leaf(21);
+    /// root.add_leaf(30);
+    ///
+    /// let leaves: Vec<i32> = root.leaves().iter().map(Node::value).collect();
+    /// assert_eq!(leaves, vec![21, 30]);
+    /// ```
+    #[must_use]
+    pub fn leaves(&self) -> Vec<Self> {
+        let mut leaves = vec![];
+        self.pointer.leaves(&mut leaves);
+        leaves.iter().map(Self::from).collect()
+    }
+
+    /// returns the nodes exactly `depth` levels below this [Node], ordered left to right
+    ///
+    /// same as one entry of [`Node::levels()`] without building the other levels
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::balanced_from(&[1, 2, 3, 4, 5, 6, 7], 2).unwrap();
+    /// let leaves: Vec<i32> = root.nodes_at_depth(2).iter().map(|node| node.value()).collect();
+    ///
+    /// assert_eq!(leaves, vec![4, 5, 6, 7]);
+    /// assert_eq!(root.nodes_at_depth(0), vec![root]);
+    /// ```
+    #[must_use]
+    pub fn nodes_at_depth(&self, depth: usize) -> Vec<Self> {
+        let mut nodes = vec![];
+        self.pointer.nodes_at_depth(depth, &mut nodes);
+        nodes.iter().map(Self::from).collect()
+    }
+
+    /// returns the values present in both the subtree of this [Node] and the subtree of `other`
+    ///
+    /// each common value is returned once, in the pre-order of `other`
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let a = Node::new(1);
+    /// a.add_leaf(2);
+    /// a.add_leaf(3);
+    /// let b = Node::new(3);
+    /// b.add_leaf(4);
+    /// b.add_leaf(1);
+    ///
+    /// assert_eq!(a.common_values_with(&b), vec![3, 1]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn common_values_with(&self, other: &Self) -> Vec<T> where T: Clone + Hash + Eq {
+        let mut mine = HashSet::new();
+        self.pointer.visit(&mut |node| {
+            mine.insert(node.value().clone());
+        });
+
+        let mut common = vec![];
+        other.pointer.visit(&mut |node| {
+            if mine.remove(&node.value()) {
+                common.push(node.value().clone());
+            }
+        });
+        common
+    }
+
+    /// compares the values of this subtree with the values of `other`, ignoring the structure
+    ///
+    /// see [`TreeDiff`] for the ordering of the values
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let old = Node::new(1);
+    /// old.add_leaf(2);
+    /// old.add_leaf(3);
+    /// let new = Node::new(1);
+    /// new.add_leaf(3);
+    /// new.add_leaf(4);
+    ///
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.only_in_self, vec![2]);
+    /// assert_eq!(diff.only_in_other, vec![4]);
+    /// assert_eq!(diff.in_both, vec![1, 3]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> TreeDiff<T> where T: Clone + Hash + Eq {
+        let mine = self.unique_values();
+        let theirs = other.unique_values();
+        let mine_set: HashSet<&T> = mine.iter().collect();
+        let theirs_set: HashSet<&T> = theirs.iter().collect();
+
+        let (in_both, only_in_self) = mine.iter().cloned().partition(|value| theirs_set.contains(value));
+        let only_in_other = theirs.iter().filter(|value| !mine_set.contains(value)).cloned().collect();
+
+        TreeDiff { only_in_self, only_in_other, in_both }
+    }
+
+    /// internal method to get every value of the subtree once, in pre-order
+    #[cfg(feature = "std")]
+    fn unique_values(&self) -> Vec<T> where T: Clone + Hash + Eq {
+        let mut seen = HashSet::new();
+        let mut values = vec![];
+        self.pointer.visit(&mut |node| {
+            if seen.insert(node.value().clone()) {
+                values.push(node.value().clone());
+            }
+        });
+        values
+    }
+
+    /// returns a lazy iterator over the subtree of a [Node] in the given [`TraversalOrder`], including the Node itself
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, TraversalOrder};
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// root.add_leaf(30);
+    /// child.add_leaf(21);
+    ///
+    /// let pre: Vec<i32> = root.walk(TraversalOrder::PreOrder).map(|node| node.value()).collect();
+    /// let post: Vec<i32> = root.walk(TraversalOrder::PostOrder).map(|node| node.value()).collect();
+    /// let bfs: Vec<i32> = root.walk(TraversalOrder::BreadthFirst).map(|node| node.value()).collect();
+    /// assert_eq!(pre, vec![10, 20, 21, 30]);
+    /// assert_eq!(post, vec![21, 20, 30, 10]);
+    /// assert_eq!(bfs, vec![10, 20, 30, 21]);
+    /// ```
+    #[must_use]
+    pub fn walk(&self, order: TraversalOrder) -> Walk<T> {
+        Walk::new(&self.pointer, order)
+    }
+
+    /// calls `f` with every [Node] of the subtree in pre-order and its depth below this Node
+    ///
+    /// the [`WalkControl`] returned by `f` decides whether the walk descends into the children of the Node,
+    /// skips them or ends right away
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, WalkControl};
+    /// let root = Node::new("/");
+    /// let hidden = Node::new(".git");
+    /// root.add_child(&hidden);
+    /// hidden.add_leaf("config");
+    /// root.add_leaf("src");
+    ///
+    /// let mut listed = vec![];
+    /// root.visit(|node, depth| {
+    ///     listed.push((node.value(), depth));
+    ///     if node.value().starts_with('.') { WalkControl::SkipSubtree } else { WalkControl::Continue }
+    /// });
+    /// assert_eq!(listed, vec![("/", 0), (".git", 1), ("src", 1)]);
+    /// ```
+    pub fn visit<F: FnMut(&Self, usize) -> WalkControl>(&self, mut f: F) {
+        self.pointer.visit_controlled(&mut |node, depth| f(&Self::from(node), depth));
+    }
+
+    /// returns a lazy pre-order iterator over the subtree of a [Node], without the Node itself
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// root.add_leaf(30);
+    /// child.add_leaf(21);
+    ///
+    /// let values: Vec<i32> = root.descendants().map(|node| node.value()).collect();
+    /// assert_eq!(values, vec![20, 21, 30]);
+    /// assert_eq!(Node::new(1).descendants().count(), 0);
+    /// ```
+    #[must_use]
+    pub fn descendants(&self) -> Walk<T> {
+        Walk::descendants(&self.pointer)
+    }
+
+    /// returns a lazy pre-order iterator over the subtree of a [Node], the one `for node in &root` uses
+    ///
+    /// same as [`Node::iter_dfs()`]
+    #[must_use]
+    pub fn iter(&self) -> Walk<T> {
+        self.iter_dfs()
+    }
+
+    /// returns a lazy depth-first iterator over the subtree of a [Node], parents before their children
+    ///
+    /// same as [`Node::walk()`] with [`TraversalOrder::PreOrder`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// root.add_leaf(30);
+    /// child.add_leaf(21);
+    ///
+    /// let values: Vec<i32> = root.iter_dfs().map(|node| node.value()).collect();
+    /// assert_eq!(values, vec![10, 20, 21, 30]);
+    /// ```
+    #[must_use]
+    pub fn iter_dfs(&self) -> Walk<T> {
+        self.walk(TraversalOrder::PreOrder)
+    }
+
+    /// returns a lazy depth-first iterator over the subtree of a [Node], children before their parents
+    ///
+    /// same as [`Node::walk()`] with [`TraversalOrder::PostOrder`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// root.add_leaf(30);
+    /// child.add_leaf(21);
+    ///
+    /// let values: Vec<i32> = root.iter_dfs_post().map(|node| node.value()).collect();
+    /// assert_eq!(values, vec![21, 20, 30, 10]);
+    /// ```
+    #[must_use]
+    pub fn iter_dfs_post(&self) -> Walk<T> {
+        self.walk(TraversalOrder::PostOrder)
+    }
+
+    /// returns a lazy breadth-first iterator over the subtree of a [Node], level by level from left to right
+    ///
+    /// same as [`Node::walk()`] with [`TraversalOrder::BreadthFirst`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// root.add_leaf(30);
+    /// child.add_leaf(21);
+    ///
+    /// let values: Vec<i32> = root.iter_bfs().map(|node| node.value()).collect();
+    /// assert_eq!(values, vec![10, 20, 30, 21]);
+    /// ```
+    #[must_use]
+    pub fn iter_bfs(&self) -> Walk<T> {
+        self.walk(TraversalOrder::BreadthFirst)
+    }
+
+    /// calls `f` on every [Node] of the subtree in post-order, children before their parent
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// root.add_leaf(20);
+    /// root.add_leaf(30);
+    ///
+    /// let mut visited = vec![];
+    /// root.apply_postorder(|node| visited.push(node.value()));
+    /// assert_eq!(visited, vec![20, 30, 10]);
+    /// ```
+    pub fn apply_postorder<F: FnMut(&Self)>(&self, mut f: F) {
+        self.pointer.visit_postorder(&mut |node| f(&Self::from(node)));
+    }
+
+    /// detaches this [Node] from its parent and returns it as the root of a new tree
+    ///
+    /// the subtree moves along, returns [None] if this Node already is a root
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// child.add_leaf(30);
+    ///
+    /// let split = child.split_off().unwrap();
+    /// assert!(split.is_root());
+    /// assert!(root.find(&30).is_none());
+    /// assert_eq!(split.children_values(), vec![30]);
+    /// assert!(root.split_off().is_none());
+    /// ```
+    #[must_use]
+    pub fn split_off(&self) -> Option<Self> {
+        (!self.is_root()).then(|| self.detach())
+    }
+
+    /// unlinks this [Node] from its parent and returns it as the root of its own tree
+    ///
+    /// the descendants stay attached to it. Unlike [`Node::split_off()`] a root is returned as is
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// child.add_leaf(30);
+    ///
+    /// let detached = child.detach();
+    /// assert_eq!(detached, child);
+    /// assert!(child.is_root());
+    /// assert_eq!(child.children_values(), vec![30]);
+    /// assert_eq!(root.detach(), root);
+    /// ```
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+    pub fn detach(&self) -> Self {
+        self.pointer.detach();
+        Self::from(&self.pointer)
+    }
+
+    /// folds the subtree of a [Node] bottom-up into a single result
+    ///
+    /// every Node combines its value with the results of its children using `f`, leaves get an empty [Vec].
+    /// See [`Node::fold_tree()`] to handle leaves separately
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(1);
+    /// let child = Node::new(2);
+    /// root.add_child(&child);
+    /// child.add_leaf(3);
+    ///
+    /// let height = root.fold(|_, children: Vec<usize>| children.into_iter().max().map_or(0, |max| max + 1));
+    /// assert_eq!(height, 2);
+    /// ```
+    pub fn fold<A, F: Fn(&T, Vec<A>) -> A>(&self, f: F) -> A {
+        self.fold_tree(|value| f(value, vec![]), &f)
+    }
+
+    /// folds the subtree of a [Node] bottom-up into a single result
+    ///
+    /// leaves are turned into a result with `leaf`, every other Node combines its value with the results
+    /// of its children using `node`. Uses an explicit stack instead of recursion
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(1);
+    /// let child = Node::new(2);
+    /// root.add_child(&child);
+    /// child.add_leaf(3);
+    /// root.add_leaf(4);
+    ///
+    /// let sum = root.fold_tree(|value| *value, |value, children: Vec<i32>| value + children.iter().sum::<i32>());
+    /// assert_eq!(sum, 10);
+    /// ```
+    pub fn fold_tree<A, L, N>(&self, leaf: L, node: N) -> A
+    where
+        L: Fn(&T) -> A,
+        N: Fn(&T, Vec<A>) -> A,
+    {
+        let mut results = vec![];
+        for current in self.walk(TraversalOrder::PostOrder) {
+            let child_count = current.pointer.children().borrow().len();
+            let result = if child_count == 0 {
+                leaf(&current.borrow_value())
+            } else {
+                let child_results = results.split_off(results.len() - child_count);
+                node(&current.borrow_value(), child_results)
+            };
+            if current == *self {
+                return result;
+            }
+            results.push(result);
+        }
+        unreachable!("post-order ends with the calling node")
+    }
+
+    /// removes exactly `node` and its children from the tree of this [Node], compared by identity
+    ///
+    /// `node` can be anywhere in the tree, not only below this Node.
+    /// Returns false if `node` is this Node, a root or not part of the tree
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10).allow_duplicates();
+    /// root.add_leaf(20);
+    /// let second = Node::new(20);
+    /// root.add_child(&second);
+    ///
+    /// assert!(root.remove(&second));
+    /// assert!(!root.remove(&second));
+    /// assert!(!root.remove(&root));
+    /// assert_eq!(root.children_values(), vec![20]);
+    /// ```
+    #[must_use]
+    pub fn remove(&self, node: &Self) -> bool {
+        node != self && Rc::ptr_eq(&node.pointer.get_root(), &self.pointer.get_root()) && node.pointer.detach()
+    }
+
+    /// get the root [Node]
+    ///
+    /// if this Node has no parents, this Node is the root Node
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let a_child = Node::new(20);
+    /// let child_of_child = Node::new(30);
+    /// root.add_child(&a_child);
+    /// a_child.add_child(&child_of_child);
+    ///
+    /// assert_eq!(child_of_child.get_root(), root);
+    /// ```
+    #[must_use]
+    pub fn get_root(&self) -> Self {
+        Self::from(&self.pointer.get_root())
+    }
+
+    /// gets the number of strong pointers of this Node
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// assert_eq!(root.rc_count(), 1);
+    /// ```
+    #[must_use]
+    pub fn rc_count(&self) -> usize  {
+     Rc::strong_count(&self.pointer)
+    }
+
+    /// creates a [`WeakNode`] that refers to this [Node] without keeping it alive
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let node = Node::new(10);
+    /// let weak = node.downgrade();
+    ///
+    /// assert_eq!(weak.upgrade().unwrap(), node);
+    /// assert_eq!(node.rc_count(), 1);
+    /// drop(node);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    #[must_use]
+    pub fn downgrade(&self) -> WeakNode<T> {
+        WeakNode::from(&self.pointer)
+    }
+}
+
+// everything that looks at values, including the uniqueness check when adding
+#[allow(unused)]
+impl<T> Node<T> where T: PartialEq {
+    /// builds a complete tree from a slice in level order
+    ///
+    /// the first value is the root, the next `branching` values are its children and so on.
+    /// returns [None] if the slice is empty, `branching` is 0 or a value is rejected by [`Node::add_child()`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::balanced_from(&[1, 2, 3, 4, 5], 2).unwrap();
+    ///
+    /// assert_eq!(root.value(), 1);
+    /// assert_eq!(root.children_values(), vec![2, 3]);
+    /// assert_eq!(root.find(&2).unwrap().children_values(), vec![4, 5]);
+    /// assert!(Node::<i32>::balanced_from(&[], 2).is_none());
+    /// ```
+    #[must_use]
+    pub fn balanced_from(values: &[T], branching: usize) -> Option<Self> where T: Clone {
+        if values.is_empty() || branching == 0 {
+            return None;
+        }
+
+        let nodes: Vec<Self> = values.iter().cloned().map(Self::new).collect();
+        for (idx, node) in nodes.iter().enumerate().skip(1) {
+            if !nodes[(idx - 1) / branching].add_child(node) {
+                return None;
+            }
+        }
+        nodes.into_iter().next()
+    }
+
+    /// replaces the value of a [Node] and returns the old one, unless another Node of the tree has the new value
+    ///
+    /// trees with [`TreePolicy::AllowDuplicates`] accept every value
+    /// ## Panics
+    /// if the value is currently borrowed through [`Node::borrow_value()`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    ///
+    /// assert_eq!(child.try_set_value(10), Err(10));
+    /// assert_eq!(child.try_set_value(30), Ok(20));
+    /// assert_eq!(child.try_set_value(30), Ok(30));
+    /// ```
+    /// ## Errors
+    /// gives the rejected value back if it collides with another Node
+    pub fn try_set_value(&self, new: T) -> Result<T, T> {
+        if self.pointer.collides_with(&new) {
+            return Err(new);
+        }
+        Ok(self.set_value(new))
+    }
+
+    /// changes the value of a [Node] through `f`, unless the result collides with another Node of the tree
+    ///
+    /// `f` works on a copy, the value of the Node only changes if the result is accepted.
+    /// Returns true if the value was updated
+    /// ## Panics
+    /// if the value is currently borrowed through [`Node::borrow_value()`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(1);
+    /// let child = Node::new(2);
+    /// root.add_child(&child);
+    ///
+    /// assert!(!child.update(|value| *value -= 1));
+    /// assert!(child.update(|value| *value *= 10));
+    /// assert_eq!(child.value(), 20);
+    /// ```
+    pub fn update<F: FnOnce(&mut T)>(&self, f: F) -> bool where T: Clone {
+        let mut new = self.value();
+        f(&mut new);
+        self.try_set_value(new).is_ok()
+    }
+
+    /// adds a child to a [Node] if the child or any of its children are not in the tree
+    ///
+    /// see [`TreePolicy`] to lift that restriction,
+    /// and [`Node::add_child_indexed()`] for a faster check on large trees
+    ///
+    /// returns true if it added, else false. Use [`Node::try_add_child()`] to find out why a child was rejected
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// let should_be_true = root.add_child(&child);
+    /// let should_be_false = root.add_child(&child);
+    ///
+    /// assert_eq!(should_be_true, true);
+    /// assert_eq!(should_be_false, false);
+    /// ```
+    #[must_use]
+    pub fn add_child(&self, child: &Self) -> bool {
+        self.try_add_child(child).is_ok()
+    }
+
+    /// adds a child to a [Node] like [`Node::add_child()`], but tells why a child was rejected
+    ///
+    /// the child has to be a root, detach it from its old parent first.
+    /// The checks run in the order of the [`AddError`] variants
+    /// ## Example
+    /// ```
+    /// use a_ntree::{AddError, Node};
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    ///
+    /// assert_eq!(root.try_add_child(&child), Ok(()));
+    /// assert_eq!(root.try_add_child(&child), Err(AddError::AlreadyHasParent));
+    /// assert_eq!(root.try_add_child(&Node::new(20)), Err(AddError::DuplicateValue));
+    /// assert_eq!(child.try_add_child(&root), Err(AddError::WouldCreateCycle));
+    /// ```
+    /// ## Errors
+    /// returns the reason if the child can't be added
+    pub fn try_add_child(&self, child: &Self) -> Result<(), AddError> {
+        self.pointer.add_child(&child.pointer)
+    }
+
+    /// adds a child to a [Node] at `index` among its children, shifting the later children to the right
+    ///
+    /// returns false if `index` is past the end of the children or the same checks as [`Node::add_child()`] reject it
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// root.add_leaf(1);
+    /// root.add_leaf(3);
+    ///
+    /// assert!(root.insert_child_at(1, &Node::new(2)));
+    /// assert!(!root.insert_child_at(5, &Node::new(4)));
+    /// assert_eq!(root.children_values(), vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn insert_child_at(&self, index: usize, child: &Self) -> bool {
+        index <= self.pointer.children().borrow().len() && self.pointer.insert_child(index, &child.pointer).is_ok()
+    }
+
+    /// adds `new_sibling` to the parent of this [Node], directly before this Node
+    ///
+    /// returns false if this Node is the root or the same checks as [`Node::add_child()`] reject it
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// let child = Node::new(2);
+    /// root.add_child(&child);
+    ///
+    /// assert!(child.insert_before(&Node::new(1)));
+    /// assert!(!root.insert_before(&Node::new(3)));
+    /// assert_eq!(root.children_values(), vec![1, 2]);
+    /// ```
+    #[must_use]
+    pub fn insert_before(&self, new_sibling: &Self) -> bool {
+        self.insert_sibling(new_sibling, 0)
+    }
+
+    /// adds `new_sibling` to the parent of this [Node], directly after this Node
+    ///
+    /// returns false if this Node is the root or the same checks as [`Node::add_child()`] reject it
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// let child = Node::new(1);
+    /// root.add_child(&child);
+    ///
+    /// assert!(child.insert_after(&Node::new(2)));
+    /// assert!(!root.insert_after(&Node::new(3)));
+    /// assert_eq!(root.children_values(), vec![1, 2]);
+    /// ```
+    #[must_use]
+    pub fn insert_after(&self, new_sibling: &Self) -> bool {
+        self.insert_sibling(new_sibling, 1)
+    }
+
+    /// internal method to insert a sibling at `offset` from the position of this [Node]
+    fn insert_sibling(&self, new_sibling: &Self, offset: usize) -> bool {
+        self.pointer.position()
+            .is_some_and(|(parent, idx)| parent.insert_child(idx + offset, &new_sibling.pointer).is_ok())
+    }
+
+    /// adds a value directly as a child to a [`Node`]
+    ///
+    /// same as [`Node::add_child()`] but without the need to create a new Node
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// root.add_leaf(30);
+    ///
+    /// assert_eq!(root.children().get(0).unwrap().value(), 30);
+    /// ```
+    pub fn add_leaf(&self, leaf: T) -> bool {
+        self.add_child(&Self::new(leaf))
+    }
+
+    /// moves this [Node] and its children below `new_parent`, as its last child
+    ///
+    /// returns false and leaves the tree unchanged if `new_parent` is this Node or one of its descendants,
+    /// or if the destination tree rejects the subtree like [`Node::add_child()`] does
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let left = Node::new(20);
+    /// let right = Node::new(30);
+    /// root.add_child(&left);
+    /// root.add_child(&right);
+    ///
+    /// assert!(right.move_to(&left));
+    /// assert_eq!(right.parent().unwrap(), left);
+    /// assert!(!left.move_to(&right));
+    /// ```
+    #[must_use]
+    pub fn move_to(&self, new_parent: &Self) -> bool {
+        self.reparent(new_parent).is_ok()
+    }
+
+    /// moves this [Node] and its children below `new_parent` like [`Node::move_to()`], but tells why it failed
+    ///
+    /// on an error the subtree stays at its old position
+    /// ## Example
+    /// ```
+    /// use a_ntree::{AddError, Node};
+    /// let root = Node::new(10);
+    /// let left = Node::new(20);
+    /// let right = Node::new(30);
+    /// root.add_child(&left);
+    /// root.add_child(&right);
+    ///
+    /// assert_eq!(right.reparent(&left), Ok(()));
+    /// assert_eq!(left.reparent(&right), Err(AddError::WouldCreateCycle));
+    /// assert_eq!(right.parent().unwrap(), left);
+    /// ```
+    /// ## Errors
+    /// returns the reason if `new_parent` can't take the subtree,
+    /// [`AddError::AlreadyHasParent`] is never returned
+    pub fn reparent(&self, new_parent: &Self) -> Result<(), AddError> {
+        self.pointer.move_to(&new_parent.pointer)
+    }
+
+    /// searches a [Node] by value - starting from the calling Node inclusive
+    ///
+    /// returns the first Node found in pre-order or [None] if the value doesnt exist.
+    /// Only trees with [`TreePolicy::AllowDuplicates`] can have more than one match, see [`Node::find_all()`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// root.add_leaf(20);
+    /// root.add_leaf(30);
+    ///
+    /// assert_eq!(root.find(&30).unwrap().value(), 30);
+    /// assert_eq!(root.find(&20).unwrap().value(), 20);
+    /// assert_eq!(root.find(&10).unwrap().value(), 10);
+    /// assert!(root.find(&999999).is_none());
+    ///```
+    pub fn find(&self, value: &T) -> Option<Self> {
+        if let Some(found) = self.pointer.find(value) {
+            return Some(Self::from(&found));
+        }
+        None
+    }
+
+    /// searches every [Node] with a value - starting from the calling Node inclusive
+    ///
+    /// returns the matches in pre-order, or an empty [Vec] if the value doesnt exist
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10).allow_duplicates();
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// child.add_leaf(10);
+    ///
+    /// let found = root.find_all(&10);
+    /// assert_eq!(found.len(), 2);
+    /// assert_eq!(found[0], root);
+    /// assert_eq!(found[1].parent().unwrap(), child);
+    /// assert!(root.find_all(&30).is_empty());
+    /// ```
+    #[must_use]
+    pub fn find_all(&self, value: &T) -> Vec<Self> {
+        let mut found = vec![];
+        self.pointer.find_all(value, &mut found);
+        found.iter().map(Self::from).collect()
+    }
+
+    /// searches a [Node] by value like [`Node::find()`] and returns the nodes leading to it
+    ///
+    /// the path starts with the calling Node and ends with the found Node
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// let child = Node::new(20);
+    /// root.add_child(&child);
+    /// child.add_leaf(30);
+    ///
+    /// let path: Vec<i32> = root.find_path(&30).unwrap().iter().map(|node| node.value()).collect();
+    /// assert_eq!(path, vec![10, 20, 30]);
+    /// assert!(root.find_path(&40).is_none());
+    /// ```
+    #[must_use]
+    pub fn find_path(&self, value: &T) -> Option<Vec<Self>> {
+        let mut path = vec![];
+        self.pointer.find_path(value, &mut path)
+            .then(|| path.iter().map(Self::from).collect())
+    }
+
+    /// checks the links between this [Node], its ancestors and its subtree
+    ///
+    /// every child has to point back at its parent, no Node may be reachable twice and no parent may be dangling.
+    /// If the tree keeps values unique, the values of the subtree are checked as well, which takes quadratic time
+    /// ## Example
+    /// ```
+    /// use a_ntree::{InvariantError, Node};
+    /// let root = Node::new(10);
+    /// root.add_leaf(20);
+    /// assert_eq!(root.validate(), Ok(()));
+    ///
+    /// assert!(root.add_child_unchecked(&Node::new(20)));
+    /// assert_eq!(root.validate(), Err(InvariantError::DuplicateValue));
+    /// ```
+    /// ## Errors
+    /// returns the first broken invariant that was found
+    pub fn validate(&self) -> Result<(), InvariantError> {
+        self.pointer.validate_links()?;
+        if !self.allows_duplicates() && self.pointer.has_duplicate_values() {
+            return Err(InvariantError::DuplicateValue);
+        }
+        Ok(())
+    }
+
+    /// returns true if both subtrees have the same shape and equal values in the same child order
+    ///
+    /// unlike `==`, which compares [Node]s by identity, this compares the trees below them
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let a = Node::new(10);
+    /// a.add_leaf(20);
+    /// let b = Node::new(10);
+    /// b.add_leaf(20);
+    ///
+    /// assert_ne!(a, b);
+    /// assert!(a.structural_eq(&b));
+    /// b.add_leaf(30);
+    /// assert!(!a.structural_eq(&b));
+    /// ```
+    #[must_use]
+    pub fn structural_eq(&self, other: &Self) -> bool {
+        self.pointer.structural_eq(&other.pointer)
+    }
+
+    /// returns true if some [Node] in the subtree of `other` is [structurally equal](Node::structural_eq) to this Node
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(1);
+    /// let child = Node::new(2);
+    /// root.add_child(&child);
+    /// child.add_leaf(3);
+    ///
+    /// let pattern = Node::new(2);
+    /// pattern.add_leaf(3);
+    /// assert!(pattern.is_subtree_of(&root));
+    /// assert!(!Node::new(2).is_subtree_of(&root));
+    /// ```
+    #[must_use]
+    pub fn is_subtree_of(&self, other: &Self) -> bool {
+        other.iter_dfs().any(|node| self.structural_eq(&node))
+    }
+
+    /// grafts a copy of `other` into the tree of this [Node], matching Nodes by value
+    ///
+    /// the Nodes of `other` are placed in pre-order below the Node their parent was matched to,
+    /// the root of `other` goes below the calling Node. A value that isn't in the tree yet is added as a new child,
+    /// a value that is already in the tree is resolved by the [`MergeStrategy`].
+    /// The tree is looked up through one hash index built up front, `other` itself is left unchanged
+    /// ## Example
+    /// ```
+    /// use a_ntree::{tree, MergeStrategy};
+    /// let root = tree! { 1 => [2 => [3], 4] };
+    /// let other = tree! { 2 => [4, 5 => [6]] };
+    ///
+    /// root.merge(&other, MergeStrategy::Skip).unwrap();
+    /// assert_eq!(root.children_values(), vec![2, 4]);
+    /// assert_eq!(root.find(&2).unwrap().children_values(), vec![3, 5]);
+    /// assert_eq!(root.find(&6).unwrap().parent().unwrap().value(), 5);
+    ///
+    /// root.merge(&tree! { 2 => [7] }, MergeStrategy::Replace).unwrap();
+    /// assert_eq!(root.find(&2).unwrap().children_values(), vec![7]);
+    /// assert!(root.find(&3).is_none());
+    /// ```
+    /// ## Errors
+    /// stops with [`AddError::DuplicateValue`] if a [`MergeStrategy::Combine`] result collides with another value
+    /// of the tree, the Nodes merged so far stay in place
+    #[cfg(feature = "std")]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn merge(&self, other: &Self, strategy: MergeStrategy<'_, T>) -> Result<(), AddError> where T: Clone + Hash + Eq {
+        let root = self.get_root();
+        let unique = !root.allows_duplicates();
+        let mut lookup = HashMap::new();
+        for node in root.iter_dfs() {
+            lookup.entry(node.value()).or_insert(node);
+        }
+
+        let mut stack = vec![(Self::from(&self.pointer), other.deep_clone())];
+        while let Some((parent, incoming)) = stack.pop() {
+            let value = incoming.value();
+            let target = match lookup.get(&value).map(|node| Self::from(&node.pointer)) {
+                None => {
+                    let copy = Self::new(value.clone());
+                    parent.pointer.add_child_unchecked(&copy.pointer);
+                    lookup.insert(value, Self::from(&copy.pointer));
+                    copy
+                }
+                Some(existing) => {
+                    match &strategy {
+                        MergeStrategy::Skip => {}
+                        // a match above its new parent can't move there and is kept like with Skip
+                        MergeStrategy::Replace if parent == existing || parent.ancestors().any(|node| node == existing) => {}
+                        MergeStrategy::Replace => {
+                            if existing.parent().as_ref() != Some(&parent) {
+                                existing.pointer.detach();
+                                parent.pointer.link_child(parent.children().len(), &existing.pointer);
+                            }
+                            existing.set_value(value);
+                            for child in existing.drain_children() {
+                                for node in child.iter_dfs() {
+                                    let value = node.value();
+                                    if lookup.get(&value) == Some(&node) {
+                                        lookup.remove(&value);
+                                    }
+                                }
+                            }
+                        }
+                        MergeStrategy::Combine(combine) => {
+                            let combined = combine(&existing.borrow_value(), &value);
+                            if unique && lookup.get(&combined).is_some_and(|node| *node != existing) {
+                                return Err(AddError::DuplicateValue);
+                            }
+                            let old = existing.set_value(combined.clone());
+                            if lookup.get(&old) == Some(&existing) {
+                                lookup.remove(&old);
+                            }
+                            lookup.entry(combined).or_insert_with(|| Self::from(&existing.pointer));
+                        }
+                    }
+                    existing
+                }
+            };
+            for child in incoming.children().into_iter().rev() {
+                stack.push((Self::from(&target.pointer), child));
+            }
+        }
+        Ok(())
+    }
+
+    /// removes the first child [Node] from this Node and all children
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    ///
+    /// let root = Node::new(10);
+    /// root.add_leaf(30);
+    /// root.add_leaf(40);
+    /// // root has 2 children
+    /// root.remove_node(&40);
+    /// // root has 1 child
+    /// assert_eq!(root.children().len(), 1);
+    /// assert!(root.find(&40).is_none());
+    /// ```
+    pub fn remove_node(&self, value: &T) -> Option<Self> {
+        self.pointer.remove_node(value).map(|raw_node| Self::from(&raw_node))
+    }
+
+    /// removes the first [Node] with a value, its children take its place in its parent
+    ///
+    /// the removed Node is returned without parent and children. Returns [None] if the value doesn't exist
+    /// or belongs to the root of the tree, which has no parent to take the children
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(0);
+    /// let group = Node::new(1);
+    /// root.add_child(&group);
+    /// group.add_leaf(2);
+    /// group.add_leaf(3);
+    /// root.add_leaf(4);
+    ///
+    /// assert_eq!(root.remove_node_promote(&1).unwrap(), group);
+    /// assert_eq!(root.children_values(), vec![2, 3, 4]);
+    /// assert!(group.children().is_empty());
+    /// assert!(root.remove_node_promote(&0).is_none());
+    /// ```
+    pub fn remove_node_promote(&self, value: &T) -> Option<Self> {
+        self.pointer.remove_node_promote(value).map(|raw_node| Self::from(&raw_node))
     }
 }
@@ -0,0 +1,28 @@
+use alloc::boxed::Box;
+use core::fmt::{Debug, Formatter, Result};
+
+/// how [`Node::merge()`](crate::Node::merge) resolves a value that is in both trees
+pub enum MergeStrategy<'a, T> {
+    /// keeps the existing Node where it is with its value and children, only new values are added below it
+    Skip,
+    /// swaps the subtree: the existing Node moves below the matched parent and takes the other value,
+    /// its children are detached and the children of the other Node take their place.
+    /// A Node that is an ancestor of the matched parent is kept like with [`MergeStrategy::Skip`]
+    Replace,
+    /// sets the value of the existing Node to the result of the closure, called with the existing and the other value.
+    ///
+    /// the Node stays where it is like with [`MergeStrategy::Skip`],
+    /// a result that collides with another value of the tree stops the merge with an error
+    #[allow(clippy::type_complexity)]
+    Combine(Box<dyn Fn(&T, &T) -> T + 'a>),
+}
+
+impl<T> Debug for MergeStrategy<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            Self::Skip => "Skip",
+            Self::Replace => "Replace",
+            Self::Combine(_) => "Combine(..)",
+        })
+    }
+}
@@ -0,0 +1,112 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::{AddError, Node};
+
+/// a value together with the values of its children, the input of [`Node::from_nested()`]
+///
+/// usually written with the [`tree!`](crate::tree) macro instead of by hand
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NestedNode<T> {
+    /// the value of the Node
+    pub value: T,
+    /// the children of the Node in order
+    pub children: Vec<Self>,
+}
+
+impl<T> NestedNode<T> {
+    /// creates a [`NestedNode`] with children
+    pub fn new(value: T, children: impl IntoIterator<Item = Self>) -> Self {
+        Self { value, children: children.into_iter().collect() }
+    }
+
+    /// creates a [`NestedNode`] without children
+    pub const fn leaf(value: T) -> Self {
+        Self { value, children: Vec::new() }
+    }
+}
+
+impl<T> From<(T, Vec<Self>)> for NestedNode<T> {
+    fn from((value, children): (T, Vec<Self>)) -> Self {
+        Self { value, children }
+    }
+}
+
+impl<T> Node<T> where T: PartialEq {
+    /// builds a whole tree at once from a value and the nested values of its children
+    ///
+    /// the same checks as [`Node::try_add_child()`] apply to every Node, so all values have to be unique
+    /// ## Example
+    /// ```
+    /// use a_ntree::{AddError, NestedNode, Node};
+    /// let root = Node::from_nested((1, vec![
+    ///     NestedNode::new(2, [NestedNode::leaf(4), NestedNode::leaf(5)]),
+    ///     NestedNode::leaf(3),
+    /// ])).unwrap();
+    ///
+    /// assert_eq!(root.children_values(), vec![2, 3]);
+    /// assert_eq!(root.find(&2).unwrap().children_values(), vec![4, 5]);
+    ///
+    /// let duplicate = Node::from_nested(NestedNode::new(1, [NestedNode::leaf(1)]));
+    /// assert_eq!(duplicate, Err(AddError::DuplicateValue));
+    /// ```
+    /// ## Errors
+    /// returns the reason of the first Node that couldn't be added
+    pub fn from_nested(nested: impl Into<NestedNode<T>>) -> Result<Self, AddError> {
+        let NestedNode { value, children } = nested.into();
+        let root = Self::new(value);
+        let mut stack = vec![(root.get_root(), children)];
+        while let Some((parent, children)) = stack.pop() {
+            for NestedNode { value, children } in children {
+                let child = Self::new(value);
+                parent.try_add_child(&child)?;
+                stack.push((child, children));
+            }
+        }
+        Ok(root)
+    }
+}
+
+/// builds a whole tree of [`Node`](crate::Node)s at once
+///
+/// every value is followed by `=> [...]` with its children, leaves stand on their own.
+/// Panics on an invalid tree, use [`Node::from_nested()`](crate::Node::from_nested) to get the error instead
+/// ## Example
+/// ```
+/// use a_ntree::tree;
+/// let root = tree! { 1 => [2 => [4, 5], 3] };
+///
+/// assert_eq!(root.children_values(), vec![2, 3]);
+/// assert_eq!(root.find(&2).unwrap().children_values(), vec![4, 5]);
+/// assert_eq!(tree!("leaf").len(), 1);
+/// ```
+/// ## Panics
+/// panics if a value appears more than once
+#[macro_export]
+macro_rules! tree {
+    ($($nested:tt)+) => {
+        match $crate::Node::from_nested($crate::__nested!($($nested)+)) {
+            ::core::result::Result::Ok(root) => root,
+            ::core::result::Result::Err(error) => ::core::panic!("tree! built an invalid tree: {}", error),
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nested {
+    (@list [$($done:expr),*]) => {
+        [$($done),*]
+    };
+    (@list [$($done:expr),*] $value:expr => [$($children:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::__nested!(@list [$($done,)* $crate::__nested!($value => [$($children)*])] $($($rest)*)?)
+    };
+    (@list [$($done:expr),*] $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__nested!(@list [$($done,)* $crate::NestedNode::leaf($value)] $($($rest)*)?)
+    };
+    ($value:expr => [$($children:tt)*]) => {
+        $crate::NestedNode::new($value, $crate::__nested!(@list [] $($children)*))
+    };
+    ($value:expr) => {
+        $crate::NestedNode::leaf($value)
+    };
+}
@@ -0,0 +1,11 @@
+/// which values a tree accepts, set on the root with [`Node::with_policy()`](crate::Node::with_policy)
+///
+/// structural checks always apply: a Node can't have two parents and can't be added below itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TreePolicy {
+    /// every value appears at most once in the tree
+    #[default]
+    UniqueValues,
+    /// values may repeat, value based lookups resolve to the first match in pre-order
+    AllowDuplicates,
+}
@@ -0,0 +1,61 @@
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::ser::SerializeStruct;
+use crate::base::RawNode;
+use crate::Node;
+
+/// serializes a subtree as nested `value` and `children` fields, parent links are left out
+struct Nested<'a, T>(&'a Rc<RawNode<T>>);
+
+impl<T> Serialize for Nested<'_, T> where T: Serialize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut node = serializer.serialize_struct("Node", 2)?;
+        node.serialize_field("value", &*self.0.value())?;
+        let children = self.0.children().borrow();
+        let nested: Vec<Nested<'_, T>> = children.iter().map(Nested).collect();
+        node.serialize_field("children", &nested)?;
+        node.end()
+    }
+}
+
+/// the owned form of [Nested] that a tree is rebuilt from
+#[derive(Deserialize)]
+#[serde(rename = "Node")]
+struct NestedOwned<T> {
+    value: T,
+    #[serde(default = "Vec::new")]
+    children: Vec<Self>,
+}
+
+impl<T> NestedOwned<T> where T: PartialEq {
+    fn build(self) -> Option<Node<T>> {
+        let node = Node::new(self.value);
+        for child in self.children {
+            if !node.add_child(&child.build()?) {
+                return None;
+            }
+        }
+        Some(node)
+    }
+}
+
+/// serializes the subtree of a [Node] as `{ value, children: [..] }`,
+/// the parent of the calling Node is not part of the output
+impl<T> Serialize for Node<T> where T: Serialize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Nested(&self.pointer).serialize(serializer)
+    }
+}
+
+/// rebuilds a tree serialized by [Node]'s [Serialize] implementation, linking every child to its parent
+///
+/// the tree is built with [`Node::add_child()`], so repeated values are rejected with an error
+impl<'de, T> Deserialize<'de> for Node<T> where T: PartialEq + Deserialize<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NestedOwned::deserialize(deserializer)?
+            .build()
+            .ok_or_else(|| D::Error::custom("the tree contains a duplicate value"))
+    }
+}
@@ -0,0 +1,441 @@
+//! a thread-safe tree backed by [`Arc`] and [`RwLock`]
+//!
+//! ## Locking order
+//! every tree has a structure lock on its root. Mutations hold the structure lock of the tree they
+//! change, [`SyncNode::add_child()`] also holds the one of the attached tree, taking both in address
+//! order. Once locked, a node that stopped being a root in the meantime is released and the walk retried.
+//!
+//! while walking up to the root or down the children at most one [`RwLock`] is held at a time,
+//! and never while waiting for a structure lock, so readers and writers can't deadlock
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, Weak};
+use std::vec;
+use std::vec::Vec;
+
+#[derive(Debug)]
+struct RawSyncNode<T> {
+    value: T,
+    children: RwLock<Vec<Arc<Self>>>,
+    parent: RwLock<Weak<Self>>,
+    /// only meaningful while this node is a root, serializes all changes to its tree
+    structure: Mutex<()>,
+}
+
+fn read<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn write<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<T> RawSyncNode<T> {
+    const fn new(value: T) -> Self {
+        Self {
+            value,
+            children: RwLock::new(Vec::new()),
+            parent: RwLock::new(Weak::new()),
+            structure: Mutex::new(()),
+        }
+    }
+
+    fn parent(&self) -> Option<Arc<Self>> {
+        read(&self.parent).upgrade()
+    }
+
+    fn children(&self) -> Vec<Arc<Self>> {
+        read(&self.children).clone()
+    }
+
+    fn get_root(self: &Arc<Self>) -> Arc<Self> {
+        let mut current = Arc::clone(self);
+        while let Some(parent) = current.parent() {
+            current = parent;
+        }
+        current
+    }
+
+    fn lock_structure(&self) -> MutexGuard<'_, ()> {
+        self.structure.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// runs `f` with the root of this node's tree while holding its structure lock
+    fn with_tree_locked<R>(self: &Arc<Self>, f: impl FnOnce(&Arc<Self>) -> R) -> R {
+        loop {
+            let root = self.get_root();
+            let _guard = root.lock_structure();
+            if root.parent().is_none() {
+                return f(&root);
+            }
+        }
+    }
+
+    /// runs `f` with the roots of both trees while holding both structure locks
+    ///
+    /// `f` receives [None] if both nodes are part of the same tree
+    fn with_trees_locked<R>(
+        self: &Arc<Self>,
+        other: &Arc<Self>,
+        f: impl FnOnce(Option<(&Arc<Self>, &Arc<Self>)>) -> R,
+    ) -> R {
+        loop {
+            let mine = self.get_root();
+            let theirs = other.get_root();
+            if Arc::ptr_eq(&mine, &theirs) {
+                let _guard = mine.lock_structure();
+                if mine.parent().is_none() {
+                    return f(None);
+                }
+                continue;
+            }
+
+            let (first, second) = if Arc::as_ptr(&mine) < Arc::as_ptr(&theirs) {
+                (&mine, &theirs)
+            } else {
+                (&theirs, &mine)
+            };
+            let _first = first.lock_structure();
+            let _second = second.lock_structure();
+            if mine.parent().is_none() && theirs.parent().is_none() {
+                return f(Some((&mine, &theirs)));
+            }
+        }
+    }
+
+    fn subtree(self: &Arc<Self>, nodes: &mut Vec<Arc<Self>>) {
+        nodes.push(Arc::clone(self));
+        for child in &self.children() {
+            child.subtree(nodes);
+        }
+    }
+
+    fn position(self: &Arc<Self>) -> Option<(Arc<Self>, usize)> {
+        let parent = self.parent()?;
+        let idx = read(&parent.children).iter().position(|node| Arc::ptr_eq(node, self))?;
+        Some((parent, idx))
+    }
+}
+
+impl<T> RawSyncNode<T> where T: PartialEq {
+    fn find(self: &Arc<Self>, value: &T) -> Option<Arc<Self>> {
+        if self.value == *value {
+            return Some(Arc::clone(self));
+        }
+        self.children().iter().find_map(|child| child.find(value))
+    }
+
+    fn unique_nodes(self: &Arc<Self>, other: &Arc<Self>) -> bool {
+        let mut mine = vec![];
+        let mut theirs = vec![];
+        self.subtree(&mut mine);
+        other.subtree(&mut theirs);
+        !theirs.iter().any(|o| mine.iter().any(|m| m.value == o.value))
+    }
+
+    fn add_child(self: &Arc<Self>, child: &Arc<Self>) -> bool {
+        self.with_trees_locked(child, |roots| {
+            // only roots (and so whole trees) can be attached, which also rules out cycles
+            let Some((root, child_root)) = roots else { return false };
+            if !Arc::ptr_eq(child_root, child) || !root.unique_nodes(child) {
+                return false;
+            }
+            write(&self.children).push(Arc::clone(child));
+            *write(&child.parent) = Arc::downgrade(self);
+            true
+        })
+    }
+
+    fn find_child_position(self: &Arc<Self>, value: &T) -> Option<(Arc<Self>, usize)> {
+        for (idx, child) in self.children().iter().enumerate() {
+            if child.value == *value {
+                return Some((Arc::clone(self), idx));
+            }
+            if let Some(found) = child.find_child_position(value) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    fn remove_node(self: &Arc<Self>, value: &T) -> Option<Arc<Self>> {
+        self.with_tree_locked(|_| {
+            let (parent, idx) = if self.value == *value {
+                self.position()?
+            } else {
+                self.find_child_position(value)?
+            };
+
+            let removed = write(&parent.children).remove(idx);
+            *write(&removed.parent) = Weak::new();
+            Some(removed)
+        })
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> RawSyncNode<T> where T: Send + Sync {
+    fn par_find_by(self: &Arc<Self>, pred: &(impl Fn(&T) -> bool + Sync)) -> Option<Arc<Self>> {
+        if pred(&self.value) {
+            return Some(Arc::clone(self));
+        }
+        self.children().par_iter().find_map_first(|child| child.par_find_by(pred))
+    }
+
+    fn par_map<U>(&self, f: &(impl Fn(&T) -> U + Sync)) -> Arc<RawSyncNode<U>> where U: Send + Sync {
+        let node = Arc::new(RawSyncNode::new(f(&self.value)));
+        let children: Vec<_> = self.children().par_iter().map(|child| child.par_map(f)).collect();
+        for child in &children {
+            *write(&child.parent) = Arc::downgrade(&node);
+        }
+        *write(&node.children) = children;
+        node
+    }
+}
+
+/// a singular thread-safe Node that holds a generic value
+///
+/// works like [`Node`](crate::Node), but can be shared between threads when `T` is [Send] and [Sync]
+#[derive(Debug)]
+pub struct SyncNode<T> {
+    pointer: Arc<RawSyncNode<T>>,
+}
+
+/// [`SyncNode`] under the name of its single-threaded counterpart,
+/// so code can switch between the two with a different import
+/// ## Example
+/// ```
+/// use a_ntree::sync::Node;
+/// let root = Node::new(10);
+/// root.add_leaf(20);
+///
+/// std::thread::scope(|scope| {
+///     scope.spawn(|| assert!(root.find(&20).is_some()));
+/// });
+/// ```
+pub type Node<T> = SyncNode<T>;
+
+impl<T> PartialEq for SyncNode<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.pointer, &other.pointer)
+    }
+}
+
+impl<T> SyncNode<T> {
+    /// creates a new [`SyncNode`] with a value
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// let node = SyncNode::new(10);
+    /// let another_node = SyncNode::new(10);
+    ///
+    /// assert_ne!(node, another_node);
+    /// ```
+    pub fn new(value: T) -> Self {
+        Self { pointer: Arc::new(RawSyncNode::new(value)) }
+    }
+
+    /// internal method to get a [`RawSyncNode`] as a [`SyncNode`]
+    fn from(pointer: &Arc<RawSyncNode<T>>) -> Self {
+        Self { pointer: Arc::clone(pointer) }
+    }
+
+    /// returns the value of a [`SyncNode`] by reference
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// let node = SyncNode::new(10);
+    ///
+    /// assert_eq!(node.value(), &10);
+    /// ```
+    #[must_use]
+    pub fn value(&self) -> &T {
+        &self.pointer.value
+    }
+
+    /// returns the parent of a [`SyncNode`], the root has no parent
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// let root = SyncNode::new(10);
+    /// let child = SyncNode::new(20);
+    /// root.add_child(&child);
+    ///
+    /// assert!(root.parent().is_none());
+    /// assert_eq!(child.parent().unwrap(), root);
+    /// ```
+    #[must_use]
+    pub fn parent(&self) -> Option<Self> {
+        self.pointer.parent().as_ref().map(Self::from)
+    }
+
+    /// returns the children of a [`SyncNode`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// let root = SyncNode::new(10);
+    /// let child = SyncNode::new(30);
+    /// root.add_child(&child);
+    ///
+    /// assert_eq!(root.children(), vec![child]);
+    /// ```
+    #[must_use]
+    pub fn children(&self) -> Vec<Self> {
+        self.pointer.children().iter().map(Self::from).collect()
+    }
+
+    /// get the root [`SyncNode`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// let root = SyncNode::new(10);
+    /// let child = SyncNode::new(20);
+    /// root.add_child(&child);
+    ///
+    /// assert_eq!(child.get_root(), root);
+    /// ```
+    #[must_use]
+    pub fn get_root(&self) -> Self {
+        Self::from(&self.pointer.get_root())
+    }
+}
+
+impl<T> SyncNode<T> where T: PartialEq {
+    /// adds a child to a [`SyncNode`] if none of the values of the child's subtree are in the tree
+    ///
+    /// unlike [`Node::add_child()`](crate::Node::add_child) the child has to be a root.
+    /// returns true if it added, else false
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// let root = SyncNode::new(10);
+    /// let child = SyncNode::new(20);
+    ///
+    /// assert!(root.add_child(&child));
+    /// assert!(!root.add_child(&child));
+    /// assert!(!root.add_child(&SyncNode::new(20)));
+    /// ```
+    #[must_use]
+    pub fn add_child(&self, child: &Self) -> bool {
+        self.pointer.add_child(&child.pointer)
+    }
+
+    /// adds a value directly as a child to a [`SyncNode`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// let root = SyncNode::new(10);
+    /// root.add_leaf(30);
+    ///
+    /// assert_eq!(root.children()[0].value(), &30);
+    /// ```
+    pub fn add_leaf(&self, leaf: T) -> bool {
+        self.add_child(&Self::new(leaf))
+    }
+
+    /// searches a [`SyncNode`] by value - starting from the calling Node inclusive
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// let root = SyncNode::new(10);
+    /// root.add_leaf(20);
+    ///
+    /// assert_eq!(root.find(&20).unwrap().value(), &20);
+    /// assert!(root.find(&30).is_none());
+    /// ```
+    pub fn find(&self, value: &T) -> Option<Self> {
+        self.pointer.find(value).as_ref().map(Self::from)
+    }
+
+    /// removes the first [`SyncNode`] with a value from the subtree, together with its children
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// let root = SyncNode::new(10);
+    /// root.add_leaf(20);
+    /// let removed = root.remove_node(&20).unwrap();
+    ///
+    /// assert!(root.children().is_empty());
+    /// assert!(removed.parent().is_none());
+    /// ```
+    pub fn remove_node(&self, value: &T) -> Option<Self> {
+        self.pointer.remove_node(value).as_ref().map(Self::from)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> SyncNode<T> where T: Send + Sync {
+    /// returns a parallel iterator over the subtree of a [`SyncNode`], including the calling Node
+    ///
+    /// the work is split across sibling subtrees, so the nodes come in no particular order
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// use rayon::iter::ParallelIterator;
+    /// let root = SyncNode::new(1);
+    /// for value in 2..=100 {
+    ///     root.add_leaf(value);
+    /// }
+    ///
+    /// assert_eq!(root.par_iter().map(|node| *node.value()).sum::<i32>(), 5050);
+    /// ```
+    #[must_use]
+    pub fn par_iter(&self) -> impl ParallelIterator<Item = Self> {
+        // a piece is a single node to yield on its own and the subtrees to walk after it
+        let piece = (None, vec![Arc::clone(&self.pointer)]);
+        rayon::iter::split(piece, |(single, mut roots): (Option<Arc<RawSyncNode<T>>>, Vec<_>)| {
+            match roots.len() {
+                0 => ((single, roots), None),
+                1 if single.is_none() => {
+                    let root = roots.remove(0);
+                    let children = root.children();
+                    ((Some(root), roots), Some((None, children)))
+                }
+                len => {
+                    let rest = roots.split_off(len / 2);
+                    ((single, roots), (!rest.is_empty()).then_some((None, rest)))
+                }
+            }
+        })
+        .flat_map_iter(|(single, roots)| {
+            let mut nodes: Vec<_> = single.into_iter().collect();
+            for root in &roots {
+                root.subtree(&mut nodes);
+            }
+            nodes.into_iter().map(|node| Self { pointer: node })
+        })
+    }
+
+    /// searches the first [`SyncNode`] in pre-order for which `pred` returns true, checking sibling subtrees in parallel
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// let root = SyncNode::new(0);
+    /// for value in 1..1000 {
+    ///     root.add_leaf(value);
+    /// }
+    ///
+    /// assert_eq!(root.par_find_by(|value| value % 250 == 249).unwrap().value(), &249);
+    /// assert!(root.par_find_by(|value| *value > 1000).is_none());
+    /// ```
+    pub fn par_find_by<F: Fn(&T) -> bool + Sync>(&self, pred: F) -> Option<Self> {
+        self.pointer.par_find_by(&pred).as_ref().map(Self::from)
+    }
+
+    /// creates a new tree with the same structure, computing the values with `f` in parallel
+    /// ## Example
+    /// ```
+    /// use a_ntree::SyncNode;
+    /// let root = SyncNode::new(1);
+    /// root.add_leaf(2);
+    /// let mapped = root.par_map(|value| value * 10);
+    ///
+    /// assert_eq!(mapped.value(), &10);
+    /// assert_eq!(mapped.children()[0].value(), &20);
+    /// assert_eq!(mapped.children()[0].parent().unwrap(), mapped);
+    /// ```
+    pub fn par_map<U, F: Fn(&T) -> U + Sync>(&self, f: F) -> SyncNode<U> where U: Send + Sync {
+        SyncNode { pointer: self.pointer.par_map(&f) }
+    }
+}
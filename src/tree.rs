@@ -0,0 +1,339 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+use crate::Node;
+
+/// an owner for a whole tree that keeps track of its size
+///
+/// the number of nodes is updated on every change made through the [Tree], so [`Tree::len()`] doesn't walk the tree.
+/// Changes made directly through the [Node] handles bypass the count, [`Tree::recount()`] brings it up to date again.
+/// The same changes are reported to the observers added with [`Tree::subscribe()`]
+pub struct Tree<T> {
+    root: Option<Node<T>>,
+    len: usize,
+    observers: Vec<(SubscriptionId, Observer<T>)>,
+    next_id: usize,
+}
+
+type Observer<T> = Box<dyn FnMut(TreeEvent<'_, T>)>;
+
+/// a change made through a [Tree], passed to its observers right after it happened
+pub enum TreeEvent<'a, T> {
+    /// `child` and its subtree were added below `parent`
+    ChildAdded { parent: &'a Node<T>, child: &'a Node<T> },
+    /// `node` and its subtree were removed from below `parent`
+    NodeRemoved { parent: &'a Node<T>, node: &'a Node<T> },
+    /// `node` has a new value, `old` is the one it had before
+    ValueChanged { node: &'a Node<T>, old: &'a T },
+    /// `node` and its subtree were moved from below `old_parent` to below its new parent
+    Reparented { node: &'a Node<T>, old_parent: &'a Node<T> },
+    /// the root was replaced or taken, the new one is [`Tree::root()`]
+    RootChanged { old: Option<&'a Node<T>> },
+}
+
+impl<T> Clone for TreeEvent<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TreeEvent<'_, T> {}
+
+impl<T> Debug for TreeEvent<'_, T> where T: Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ChildAdded { parent, child } => f.debug_struct("ChildAdded").field("parent", parent).field("child", child).finish(),
+            Self::NodeRemoved { parent, node } => f.debug_struct("NodeRemoved").field("parent", parent).field("node", node).finish(),
+            Self::ValueChanged { node, old } => f.debug_struct("ValueChanged").field("node", node).field("old", old).finish(),
+            Self::Reparented { node, old_parent } => f.debug_struct("Reparented").field("node", node).field("old_parent", old_parent).finish(),
+            Self::RootChanged { old } => f.debug_struct("RootChanged").field("old", old).finish(),
+        }
+    }
+}
+
+/// identifies an observer of a [Tree], returned by [`Tree::subscribe()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(usize);
+
+impl<T> Debug for Tree<T> where T: Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Tree")
+            .field("root", &self.root)
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Default for Tree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Tree<T> {
+    /// creates an empty [Tree] without a root
+    /// ## Example
+    /// ```
+    /// use a_ntree::Tree;
+    /// let tree: Tree<i32> = Tree::new();
+    ///
+    /// assert!(tree.is_empty());
+    /// assert!(tree.root().is_none());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { root: None, len: 0, observers: Vec::new(), next_id: 0 }
+    }
+
+    /// creates a [Tree] owning `root` and its subtree, `root` is detached from its parent first
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, Tree};
+    /// let root = Node::new(10);
+    /// root.add_leaf(20);
+    /// let tree = Tree::from_root(root);
+    ///
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn from_root(root: Node<T>) -> Self {
+        let mut tree = Self::new();
+        tree.set_root(root);
+        tree
+    }
+
+    /// returns the root of the [Tree], or [None] if it is empty
+    #[must_use]
+    pub const fn root(&self) -> Option<&Node<T>> {
+        self.root.as_ref()
+    }
+
+    /// replaces the root of the [Tree] and returns the old one
+    ///
+    /// `root` is detached from its parent first
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, Tree};
+    /// let mut tree = Tree::from_root(Node::new(10));
+    /// let old = tree.set_root(Node::new(20)).unwrap();
+    ///
+    /// assert_eq!(old.value(), 10);
+    /// assert_eq!(tree.root().unwrap().value(), 20);
+    /// ```
+    pub fn set_root(&mut self, root: Node<T>) -> Option<Node<T>> {
+        root.pointer.detach();
+        self.len = root.len();
+        let old = self.root.replace(root);
+        self.notify(TreeEvent::RootChanged { old: old.as_ref() });
+        old
+    }
+
+    /// removes the root from the [Tree] and returns it, leaving the Tree empty
+    pub fn take_root(&mut self) -> Option<Node<T>> {
+        self.len = 0;
+        let old = self.root.take();
+        self.notify(TreeEvent::RootChanged { old: old.as_ref() });
+        old
+    }
+
+    /// returns the number of nodes in the [Tree] without walking it
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// returns true if the [Tree] has no root
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// returns the number of edges on the longest path from the root down to a leaf, 0 for an empty [Tree]
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.root.as_ref().map_or(0, Node::height)
+    }
+
+    /// counts the nodes of the [Tree] again, after changes made directly through the [Node] handles
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, Tree};
+    /// let mut tree = Tree::from_root(Node::new(10));
+    /// tree.root().unwrap().add_leaf(20);
+    /// assert_eq!(tree.len(), 1);
+    ///
+    /// tree.recount();
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    pub fn recount(&mut self) {
+        self.len = self.root.as_ref().map_or(0, Node::len);
+    }
+
+    /// adds an observer that is called with every change made through the [Tree]
+    ///
+    /// observers are called in the order they were added
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, Tree, TreeEvent};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// let mut tree = Tree::from_root(Node::new(10));
+    /// let root = tree.root().unwrap().get_root();
+    /// let added = Rc::new(RefCell::new(vec![]));
+    ///
+    /// let log = Rc::clone(&added);
+    /// let id = tree.subscribe(move |event| {
+    ///     if let TreeEvent::ChildAdded { child, .. } = event {
+    ///         log.borrow_mut().push(child.value());
+    ///     }
+    /// });
+    /// tree.insert_leaf(&root, 20);
+    /// assert!(tree.unsubscribe(id));
+    /// tree.insert_leaf(&root, 30);
+    ///
+    /// assert_eq!(*added.borrow(), vec![20]);
+    /// ```
+    pub fn subscribe(&mut self, observer: impl FnMut(TreeEvent<'_, T>) + 'static) -> SubscriptionId {
+        let id = SubscriptionId(self.next_id);
+        self.next_id += 1;
+        self.observers.push((id, Box::new(observer)));
+        id
+    }
+
+    /// removes an observer, returns false if it was already removed
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let before = self.observers.len();
+        self.observers.retain(|(observer, _)| *observer != id);
+        self.observers.len() < before
+    }
+
+    fn notify(&mut self, event: TreeEvent<'_, T>) {
+        for (_, observer) in &mut self.observers {
+            observer(event);
+        }
+    }
+
+    /// true if `node` is part of this [Tree]
+    fn contains(&self, node: &Node<T>) -> bool {
+        self.root.as_ref().is_some_and(|root| node.get_root() == *root)
+    }
+}
+
+impl<T> Tree<T> where T: PartialEq {
+    /// searches a [Node] of the [Tree] by value, see [`Node::find()`]
+    #[must_use]
+    pub fn find(&self, value: &T) -> Option<Node<T>> {
+        self.root.as_ref()?.find(value)
+    }
+
+    /// adds `child` and its subtree below `parent`, which has to be part of this [Tree]
+    ///
+    /// the same checks as [`Node::add_child()`] apply, use [`Tree::set_root()`] to fill an empty Tree
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, Tree};
+    /// let mut tree = Tree::from_root(Node::new(10));
+    /// let root = tree.root().unwrap().get_root();
+    /// let child = Node::new(20);
+    /// child.add_leaf(30);
+    ///
+    /// assert!(tree.insert(&root, &child));
+    /// assert!(!tree.insert(&root, &Node::new(30)));
+    /// assert!(!tree.insert(&Node::new(0), &Node::new(40)));
+    /// assert_eq!(tree.len(), 3);
+    /// ```
+    pub fn insert(&mut self, parent: &Node<T>, child: &Node<T>) -> bool {
+        if !self.contains(parent) || !parent.add_child(child) {
+            return false;
+        }
+        self.len += child.len();
+        self.notify(TreeEvent::ChildAdded { parent, child });
+        true
+    }
+
+    /// adds a value directly as a child of `parent`, see [`Tree::insert()`]
+    pub fn insert_leaf(&mut self, parent: &Node<T>, leaf: T) -> bool {
+        self.insert(parent, &Node::new(leaf))
+    }
+
+    /// removes the first [Node] with a value together with its children, see [`Node::remove_node()`]
+    ///
+    /// removing the root leaves the [Tree] empty
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, Tree};
+    /// let mut tree = Tree::from_root(Node::new(10));
+    /// let root = tree.root().unwrap().get_root();
+    /// tree.insert_leaf(&root, 20);
+    ///
+    /// assert_eq!(tree.remove(&20).unwrap().value(), 20);
+    /// assert_eq!(tree.len(), 1);
+    /// assert!(tree.remove(&10).is_some());
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn remove(&mut self, value: &T) -> Option<Node<T>> {
+        let root = self.root.as_ref()?;
+        if *root.borrow_value() == *value {
+            return self.take_root();
+        }
+        let removed = root.find(value)?;
+        let parent = removed.parent()?;
+        if !parent.remove(&removed) {
+            return None;
+        }
+        self.len -= removed.len();
+        self.notify(TreeEvent::NodeRemoved { parent: &parent, node: &removed });
+        Some(removed)
+    }
+
+    /// replaces the value of a [Node] of the [Tree] and returns the old one, see [`Node::try_set_value()`]
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, Tree};
+    /// let mut tree = Tree::from_root(Node::new(10));
+    /// let root = tree.root().unwrap().get_root();
+    /// tree.insert_leaf(&root, 20);
+    ///
+    /// assert_eq!(tree.set_value(&root, 15), Ok(10));
+    /// assert_eq!(tree.set_value(&root, 20), Err(20));
+    /// assert_eq!(tree.set_value(&Node::new(0), 30), Err(30));
+    /// ```
+    /// ## Errors
+    /// gives the value back if `node` isn't part of the Tree or the value collides with another Node
+    pub fn set_value(&mut self, node: &Node<T>, value: T) -> Result<T, T> {
+        if !self.contains(node) {
+            return Err(value);
+        }
+        let old = node.try_set_value(value)?;
+        self.notify(TreeEvent::ValueChanged { node, old: &old });
+        Ok(old)
+    }
+
+    /// moves a [Node] of the [Tree] together with its subtree below `new_parent`, see [`Node::reparent()`]
+    ///
+    /// returns false if one of the Nodes isn't part of the Tree, `node` is the root or the move is rejected
+    /// ## Example
+    /// ```
+    /// use a_ntree::{Node, Tree};
+    /// let mut tree = Tree::from_root(Node::new(1));
+    /// let root = tree.root().unwrap().get_root();
+    /// tree.insert_leaf(&root, 2);
+    /// tree.insert_leaf(&root, 3);
+    /// let (two, three) = (tree.find(&2).unwrap(), tree.find(&3).unwrap());
+    ///
+    /// assert!(tree.move_node(&three, &two));
+    /// assert_eq!(two.children_values(), vec![3]);
+    /// assert!(!tree.move_node(&two, &three));
+    /// ```
+    pub fn move_node(&mut self, node: &Node<T>, new_parent: &Node<T>) -> bool {
+        if !self.contains(node) || !self.contains(new_parent) {
+            return false;
+        }
+        let Some(old_parent) = node.parent() else { return false };
+        if node.reparent(new_parent).is_err() {
+            return false;
+        }
+        self.notify(TreeEvent::Reparented { node, old_parent: &old_parent });
+        true
+    }
+}
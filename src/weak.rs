@@ -0,0 +1,60 @@
+use alloc::rc::{Rc, Weak};
+use crate::base::RawNode;
+use crate::Node;
+
+/// a non-owning handle to a [Node], created by [`Node::downgrade()`]
+///
+/// holding a [`WeakNode`] doesn't keep the Node or its subtree alive,
+/// [`WeakNode::upgrade()`] returns [None] once every [Node] handle and the parent dropped it
+#[derive(Debug)]
+pub struct WeakNode<T> {
+    pointer: Weak<RawNode<T>>,
+}
+
+impl<T> WeakNode<T> {
+    /// creates a [`WeakNode`] that never upgrades
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { pointer: Weak::new() }
+    }
+
+    pub(crate) fn from(pointer: &Rc<RawNode<T>>) -> Self {
+        Self { pointer: Rc::downgrade(pointer) }
+    }
+
+    /// returns the [Node] if it is still alive
+    /// ## Example
+    /// ```
+    /// use a_ntree::Node;
+    /// let root = Node::new(10);
+    /// root.add_leaf(20);
+    /// let weak = root.find(&20).unwrap().downgrade();
+    ///
+    /// assert_eq!(weak.upgrade().unwrap().value(), 20);
+    /// drop(root.remove_node(&20));
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    #[must_use]
+    pub fn upgrade(&self) -> Option<Node<T>> {
+        self.pointer.upgrade().as_ref().map(Node::from)
+    }
+}
+
+impl<T> Default for WeakNode<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for WeakNode<T> {
+    fn clone(&self) -> Self {
+        Self { pointer: Weak::clone(&self.pointer) }
+    }
+}
+
+impl<T> PartialEq for WeakNode<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Weak::ptr_eq(&self.pointer, &other.pointer)
+    }
+}
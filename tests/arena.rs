@@ -0,0 +1,55 @@
+#![cfg(feature = "std")]
+
+use a_ntree::arena::Tree;
+use a_ntree::TraversalOrder;
+
+#[test]
+fn stale_id_does_not_resolve_after_reuse() {
+    let mut tree = Tree::new(0);
+    let old = tree.add_child(tree.root(), 1).unwrap();
+    assert_eq!(tree.remove(old), Some(1));
+
+    let new = tree.add_child(tree.root(), 2).unwrap();
+    assert_ne!(old, new);
+    assert!(tree.get(old).is_none());
+    assert!(tree.add_child(old, 3).is_none());
+    assert_eq!(tree.remove(old), None);
+    assert_eq!(tree.get(new), Some(&2));
+}
+
+#[test]
+fn removed_values_can_be_added_again() {
+    let mut tree = Tree::new(0);
+    let child = tree.add_child(tree.root(), 1).unwrap();
+    tree.add_child(child, 2);
+
+    assert!(tree.add_child(tree.root(), 2).is_none());
+    tree.remove(child);
+    assert!(tree.add_child(tree.root(), 2).is_some());
+    assert_eq!(tree.len(), 2);
+}
+
+#[test]
+fn root_cannot_be_removed() {
+    let mut tree = Tree::new(0);
+
+    assert_eq!(tree.remove(tree.root()), None);
+    assert_eq!(tree.len(), 1);
+}
+
+#[test]
+fn build_large_tree() {
+    let mut tree = Tree::new(0);
+    let mut parent = tree.root();
+    for value in 1..100_000 {
+        let id = tree.add_child(parent, value).unwrap();
+        if value % 10 == 0 {
+            parent = id;
+        }
+    }
+
+    assert_eq!(tree.len(), 100_000);
+    assert_eq!(tree.walk(tree.root(), TraversalOrder::PostOrder).count(), 100_000);
+    assert_eq!(tree.walk(tree.root(), TraversalOrder::PreOrder).last().map(|id| tree.get(id)), Some(Some(&99_999)));
+    assert!(tree.add_child(tree.root(), 50_000).is_none());
+}
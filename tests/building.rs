@@ -0,0 +1,118 @@
+use a_ntree::{tree, AddError, NestedNode, Node, TraversalOrder};
+
+fn level_values(root: &Node<i32>) -> Vec<Vec<i32>> {
+    root.levels().iter()
+        .map(|level| level.iter().map(|node| node.value()).collect())
+        .collect()
+}
+
+#[test]
+fn balanced_levels() {
+    let values: Vec<i32> = (1..=13).collect();
+    let root = Node::balanced_from(&values, 3).unwrap();
+
+    assert_eq!(level_values(&root), vec![
+        vec![1],
+        vec![2, 3, 4],
+        vec![5, 6, 7, 8, 9, 10, 11, 12, 13],
+    ]);
+    assert_eq!(root.find(&3).unwrap().children_values(), vec![8, 9, 10]);
+}
+
+#[test]
+fn balanced_partial_last_level() {
+    let root = Node::balanced_from(&[1, 2, 3, 4], 2).unwrap();
+
+    assert_eq!(level_values(&root), vec![vec![1], vec![2, 3], vec![4]]);
+}
+
+#[test]
+fn balanced_rejects_invalid_input() {
+    assert!(Node::balanced_from(&[1, 2, 3], 0).is_none());
+    assert!(Node::balanced_from(&[1, 2, 1], 2).is_none());
+}
+
+#[test]
+fn sorted_insertion() {
+    let root = Node::new(0);
+    for value in [50, 20, 40, 10, 30] {
+        assert!(root.add_child_sorted(&Node::new(value)));
+    }
+
+    assert_eq!(root.children_values(), vec![10, 20, 30, 40, 50]);
+    assert!(root.children().iter().all(|child| child.parent().unwrap() == root));
+}
+
+#[test]
+fn sorted_insertion_keeps_uniqueness() {
+    let root = Node::new(0);
+    assert!(root.add_child_sorted(&Node::new(20)));
+    assert!(!root.add_child_sorted(&Node::new(20)));
+    assert!(!root.add_child_sorted(&Node::new(0)));
+
+    assert_eq!(root.children_values(), vec![20]);
+}
+
+#[test]
+fn tree_macro_builds_nested_children() {
+    let root = tree! { "a" => ["b" => ["d", "e" => ["f"]], "c",] };
+
+    assert_eq!(root.walk(TraversalOrder::PreOrder).map(|node| node.value()).collect::<Vec<_>>(),
+               vec!["a", "b", "d", "e", "f", "c"]);
+    assert_eq!(root.find(&"e").unwrap().children_values(), vec!["f"]);
+    assert!(root.find(&"c").unwrap().children().is_empty());
+}
+
+#[test]
+fn tree_macro_accepts_expressions() {
+    let base = 10;
+    let root = tree! { base => [base + 1, base * 2 => [-base]] };
+
+    assert_eq!(root.children_values(), vec![11, 20]);
+    assert_eq!(root.find(&20).unwrap().children_values(), vec![-10]);
+}
+
+#[test]
+#[should_panic(expected = "tree! built an invalid tree")]
+fn tree_macro_panics_on_duplicates() {
+    let _ = tree! { 1 => [2 => [3], 3] };
+}
+
+#[test]
+fn from_nested_reports_duplicates() {
+    let nested = NestedNode::new(1, [NestedNode::new(2, [NestedNode::leaf(3)]), NestedNode::leaf(3)]);
+
+    assert_eq!(Node::from_nested(nested).unwrap_err(), AddError::DuplicateValue);
+}
+
+#[test]
+fn from_nested_keeps_deep_chains() {
+    let mut nested = NestedNode::leaf(0);
+    for value in 1..1000 {
+        nested = NestedNode::new(value, [nested]);
+    }
+    let root = Node::from_nested(nested).unwrap();
+
+    assert_eq!(root.height(), 999);
+    assert_eq!(root.len(), 1000);
+}
+
+#[test]
+fn extend_appends_leaves() {
+    let mut root = tree! { 0 => [1] };
+    root.extend(vec![2, 1, 3]);
+    root.find(&3).unwrap().extend(4..6);
+
+    assert_eq!(root.children_values(), vec![1, 2, 3]);
+    assert_eq!(root.find(&3).unwrap().children_values(), vec![4, 5]);
+}
+
+#[test]
+fn references_iterate_in_pre_order() {
+    let root = tree! { 1 => [2 => [3], 4] };
+    let values: Vec<i32> = (&root).into_iter().map(|node| node.value()).collect();
+
+    assert_eq!(values, vec![1, 2, 3, 4]);
+    assert_eq!(root.iter().count(), root.len());
+    assert!((&root).into_iter().zip(&root).all(|(a, b)| a == b));
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "std")]
+
+use a_ntree::Node;
+
+#[test]
+fn common_values_partial_overlap() {
+    let a = Node::new("html");
+    let body = Node::new("body");
+    let _ = a.add_child(&body);
+    a.add_leaf("head");
+    body.add_leaf("div");
+    body.add_leaf("p");
+
+    let b = Node::new("html");
+    let main = Node::new("main");
+    let _ = b.add_child(&main);
+    main.add_leaf("p");
+    main.add_leaf("span");
+
+    assert_eq!(a.common_values_with(&b), vec!["html", "p"]);
+    assert_eq!(b.common_values_with(&a), vec!["html", "p"]);
+}
+
+#[test]
+fn common_values_disjoint() {
+    let a = Node::new(1);
+    a.add_leaf(2);
+    let b = Node::new(3);
+    b.add_leaf(4);
+
+    assert!(a.common_values_with(&b).is_empty());
+}
+
+#[test]
+fn diff_overlapping_trees() {
+    let old = Node::new("docs");
+    let guide = Node::new("guide");
+    let _ = old.add_child(&guide);
+    guide.add_leaf("intro");
+    guide.add_leaf("setup");
+    old.add_leaf("faq");
+
+    let new = Node::new("docs");
+    let reference = Node::new("reference");
+    let _ = new.add_child(&reference);
+    reference.add_leaf("setup");
+    reference.add_leaf("api");
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.only_in_self, vec!["guide", "intro", "faq"]);
+    assert_eq!(diff.only_in_other, vec!["reference", "api"]);
+    assert_eq!(diff.in_both, vec!["docs", "setup"]);
+}
+
+#[test]
+fn diff_with_itself() {
+    let root = Node::new(1);
+    root.add_leaf(2);
+
+    let diff = root.diff(&root);
+    assert!(diff.only_in_self.is_empty());
+    assert!(diff.only_in_other.is_empty());
+    assert_eq!(diff.in_both, vec![1, 2]);
+}
+
+#[test]
+fn structural_eq_respects_child_order() {
+    let a = Node::new(0);
+    a.add_leaf(1);
+    a.add_leaf(2);
+    let b = Node::new(0);
+    b.add_leaf(2);
+    b.add_leaf(1);
+
+    assert!(a.structural_eq(&a));
+    assert!(!a.structural_eq(&b));
+    assert!(a.find(&1).unwrap().structural_eq(&b.find(&1).unwrap()));
+}
+
+#[test]
+fn structural_eq_compares_shape() {
+    let chain = Node::new(0);
+    let one = Node::new(1);
+    let _ = chain.add_child(&one);
+    one.add_leaf(2);
+    let flat = Node::new(0);
+    flat.add_leaf(1);
+    flat.add_leaf(2);
+
+    assert!(!chain.structural_eq(&flat));
+    assert!(!chain.is_subtree_of(&flat));
+    assert!(one.deep_clone().is_subtree_of(&chain));
+    assert!(chain.is_subtree_of(&chain));
+}
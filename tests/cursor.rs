@@ -0,0 +1,67 @@
+use a_ntree::{tree, AddError, CursorError, Node};
+
+fn sample() -> Node<&'static str> {
+    tree! { "root" => ["a" => ["a1", "a2"], "b", "c" => ["c1"]] }
+}
+
+#[test]
+fn failed_moves_keep_position() {
+    let root = sample();
+    let mut cursor = root.cursor();
+
+    assert_eq!(cursor.up().unwrap_err(), CursorError::NoSuchNode);
+    assert_eq!(cursor.down(3).unwrap_err(), CursorError::NoSuchNode);
+    assert_eq!(cursor.prev_sibling().unwrap_err(), CursorError::NoSuchNode);
+    assert_eq!(cursor.seek(&"z").unwrap_err(), CursorError::NotFound);
+    assert_eq!(cursor.node(), &root);
+}
+
+#[test]
+fn seek_searches_the_whole_tree() {
+    let root = sample();
+    let mut cursor = root.find(&"a2").unwrap().cursor();
+
+    cursor.seek(&"c1").unwrap().up().unwrap().prev_sibling().unwrap();
+    assert_eq!(cursor.node().value(), "b");
+    assert_eq!(cursor.root().node(), &root);
+}
+
+#[test]
+fn scripted_edits() -> Result<(), CursorError> {
+    let root = sample();
+    let mut cursor = root.cursor();
+
+    cursor.seek(&"a1")?.insert_before("a0")?.insert_after("a1.5")?;
+    assert_eq!(cursor.replace_value("a-one")?, "a1");
+    cursor.up()?.next_sibling()?.remove_here()?;
+    assert_eq!(cursor.node().value(), "c");
+    cursor.down(0)?.replace_value("c-one")?;
+
+    assert_eq!(root.children_values(), vec!["a", "c"]);
+    assert_eq!(root.find(&"a").unwrap().children_values(), vec!["a0", "a-one", "a1.5", "a2"]);
+    assert_eq!(root.find(&"c").unwrap().children_values(), vec!["c-one"]);
+    Ok(())
+}
+
+#[test]
+fn edits_report_rejections() {
+    let root = sample();
+    let mut cursor = root.cursor();
+
+    assert_eq!(cursor.insert_before("x").unwrap_err(), CursorError::AtRoot);
+    assert_eq!(cursor.remove_here().unwrap_err(), CursorError::AtRoot);
+    cursor.down(1).unwrap();
+    assert_eq!(cursor.replace_value("a").unwrap_err(), CursorError::Rejected(AddError::DuplicateValue));
+    assert_eq!(cursor.insert_after("c1").unwrap_err(), CursorError::Rejected(AddError::DuplicateValue));
+    assert_eq!(cursor.node().value(), "b");
+    assert_eq!(root.len(), 7);
+}
+
+#[test]
+fn removing_the_only_child_moves_up() {
+    let root = sample();
+    let mut cursor = root.find(&"c1").unwrap().cursor();
+
+    assert_eq!(cursor.remove_here().unwrap().value(), "c1");
+    assert_eq!(cursor.into_node().value(), "c");
+}
@@ -0,0 +1,71 @@
+use a_ntree::Node;
+
+const DEPTH: usize = 1_000_000;
+
+/// builds a chain of `DEPTH` nodes bottom-up, values count up from the root
+fn chain() -> (Node<usize>, Node<usize>) {
+    let leaf = Node::new(DEPTH - 1);
+    let mut top = leaf.get_root();
+    for value in (0..DEPTH - 1).rev() {
+        let parent = Node::new(value);
+        assert!(parent.add_child_unchecked(&top));
+        top = parent;
+    }
+    (top, leaf)
+}
+
+#[test]
+fn drop_deep_chain() {
+    let (root, leaf) = chain();
+    drop(leaf);
+    drop(root);
+}
+
+#[test]
+fn walk_up_deep_chain() {
+    let (root, leaf) = chain();
+
+    assert_eq!(leaf.get_root(), root);
+    assert_eq!(leaf.depth(), DEPTH - 1);
+    assert_eq!(leaf.path_from_root().len(), DEPTH);
+}
+
+#[test]
+fn walk_down_deep_chain() {
+    let (root, leaf) = chain();
+
+    assert_eq!(root.find(&(DEPTH - 1)).unwrap(), leaf);
+    assert_eq!(root.find_path(&(DEPTH - 1)).unwrap().len(), DEPTH);
+    assert_eq!(root.len(), DEPTH);
+    assert_eq!(root.height(), DEPTH - 1);
+    assert_eq!(root.leaves(), vec![leaf]);
+    assert_eq!(root.iter_dfs_post().count(), DEPTH);
+}
+
+#[test]
+fn edit_deep_chain() {
+    let (root, leaf) = chain();
+
+    assert!(!root.add_child(&Node::new(DEPTH - 1)));
+    assert!(root.add_leaf(DEPTH));
+    let copy = root.deep_clone();
+    assert_eq!(copy.len(), DEPTH + 1);
+
+    let removed = root.remove_node(&(DEPTH - 1)).unwrap();
+    assert_eq!(removed, leaf);
+    assert_eq!(root.len(), DEPTH);
+}
+
+#[test]
+fn build_checked_chain_bottom_up() {
+    let depth = 4_000;
+    let mut top = Node::new(depth - 1);
+    for value in (0..depth - 1).rev() {
+        let parent = Node::new(value);
+        assert!(parent.add_child(&top));
+        top = parent;
+    }
+
+    assert_eq!(top.len(), depth);
+    assert_eq!(top.height(), depth - 1);
+}
@@ -0,0 +1,76 @@
+use a_ntree::{Node, RenderStyle};
+
+#[test]
+fn display_nested_tree() {
+    let root = Node::new("root");
+    let src = Node::new("src");
+    let tests = Node::new("tests");
+    let _ = root.add_child(&src);
+    let _ = root.add_child(&tests);
+    src.add_leaf("lib.rs");
+    src.add_leaf("base.rs");
+    tests.add_leaf("display.rs");
+    root.add_leaf("README.md");
+
+    assert_eq!(root.to_string(), [
+        "root",
+        "├── src",
+        "│   ├── lib.rs",
+        "│   └── base.rs",
+        "├── tests",
+        "│   └── display.rs",
+        "└── README.md",
+    ].join("\n"));
+    assert_eq!(src.to_string(), "src\n├── lib.rs\n└── base.rs");
+}
+
+fn sample() -> Node<&'static str> {
+    let root = Node::new("root");
+    let src = Node::new("src");
+    let _ = root.add_child(&src);
+    src.add_leaf("lib.rs");
+    src.add_leaf("base.rs");
+    root.add_leaf("README.md");
+    root
+}
+
+#[test]
+fn render_ascii() {
+    assert_eq!(sample().render(RenderStyle::ascii()), [
+        "root",
+        "|-- src",
+        "|   |-- lib.rs",
+        "|   `-- base.rs",
+        "`-- README.md",
+    ].join("\n"));
+}
+
+#[test]
+fn render_with_max_depth() {
+    let root = sample();
+
+    assert_eq!(root.render(RenderStyle::default()), root.to_string());
+    assert_eq!(root.render(RenderStyle::unicode().max_depth(2)), root.to_string());
+    assert_eq!(root.render(RenderStyle::ascii().max_depth(0)), "root\n`-- ...");
+    assert_eq!(Node::new(1).render(RenderStyle::ascii().max_depth(0)), "1");
+}
+
+#[test]
+fn display_single_node() {
+    assert_eq!(Node::new(42).to_string(), "42");
+}
+
+#[test]
+fn display_deep_chain() {
+    let root = Node::new(0);
+    let mut last = root.get_root();
+    for value in 1..1000 {
+        let next = Node::new(value);
+        let _ = last.add_child(&next);
+        last = next;
+    }
+
+    let rendered = root.to_string();
+    assert_eq!(rendered.lines().count(), 1000);
+    assert_eq!(rendered.lines().last().unwrap(), format!("{}└── 999", "    ".repeat(998)));
+}
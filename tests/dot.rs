@@ -0,0 +1,32 @@
+#![cfg(feature = "dot")]
+
+use a_ntree::Node;
+
+#[test]
+fn dot_ids_follow_pre_order() {
+    let root = Node::new("root");
+    let src = Node::new("src");
+    let _ = root.add_child(&src);
+    src.add_leaf("lib.rs");
+    root.add_leaf("README.md");
+
+    assert_eq!(root.to_dot(), [
+        "digraph {",
+        "    n0 [label=\"root\"];",
+        "    n1 [label=\"src\"];",
+        "    n0 -> n1;",
+        "    n2 [label=\"lib.rs\"];",
+        "    n1 -> n2;",
+        "    n3 [label=\"README.md\"];",
+        "    n0 -> n3;",
+        "}",
+        "",
+    ].join("\n"));
+}
+
+#[test]
+fn dot_escapes_labels() {
+    let root = Node::new("say \"hi\"\nback\\slash");
+
+    assert_eq!(root.to_dot(), "digraph {\n    n0 [label=\"say \\\"hi\\\"\\nback\\\\slash\"];\n}\n");
+}
@@ -0,0 +1,150 @@
+use a_ntree::{AddError, Node, TreePolicy};
+
+#[test]
+fn remove_node_takes_first_match() {
+    let root = Node::new(0).allow_duplicates();
+    let first = Node::new(1);
+    let second = Node::new(1);
+    let _ = root.add_child(&first);
+    let _ = root.add_child(&second);
+    first.add_leaf(1);
+
+    let removed = root.remove_node(&1).unwrap();
+    assert_eq!(removed, first);
+    assert_eq!(root.children(), vec![second]);
+}
+
+#[test]
+fn remove_by_identity() {
+    let root = Node::new(0).allow_duplicates();
+    let first = Node::new(1);
+    let second = Node::new(1);
+    let _ = root.add_child(&first);
+    let _ = root.add_child(&second);
+    second.add_leaf(2);
+
+    assert!(root.remove(&second));
+    assert_eq!(root.children(), vec![first]);
+    assert!(second.is_root());
+    assert_eq!(second.children_values(), vec![2]);
+}
+
+#[test]
+fn remove_itself_keeps_node_attached() {
+    let root = Node::new(1);
+    let child = Node::new(2);
+    assert!(root.add_child(&child));
+
+    assert!(!child.remove(&child));
+    assert_eq!(child.parent().unwrap(), root);
+    assert!(root.remove(&child));
+}
+
+#[test]
+fn remove_sibling_in_same_tree() {
+    let root = Node::new(0);
+    let left = Node::new(1);
+    let right = Node::new(2);
+    let _ = root.add_child(&left);
+    let _ = root.add_child(&right);
+
+    assert!(left.remove(&right));
+    assert!(right.is_root());
+    assert_eq!(root.children_values(), vec![1]);
+}
+
+#[test]
+fn remove_outside_tree() {
+    let root = Node::new(0);
+    let _ = root.add_child(&Node::new(1));
+    let other = Node::new(5);
+    let stranger = Node::new(1);
+    let _ = other.add_child(&stranger);
+
+    assert!(!root.remove(&Node::new(1)));
+    assert!(!root.remove(&stranger));
+    assert_eq!(stranger.parent().unwrap(), other);
+    assert_eq!(root.len(), 2);
+}
+
+#[test]
+fn unchecked_builds_repeated_values() {
+    let root = Node::new(10);
+    let left = Node::new(20);
+    let right = Node::new(20);
+    assert!(root.add_child_unchecked(&left));
+    assert!(root.add_child_unchecked(&right));
+    assert!(left.add_child_unchecked(&Node::new(10)));
+    assert!(right.add_child_unchecked(&Node::new(10)));
+
+    assert_eq!(root.len(), 5);
+    assert_eq!(root.find_all(&10).len(), 3);
+    assert_eq!(root.find(&20).unwrap(), left);
+    assert_eq!(root.remove_node(&20).unwrap(), left);
+    assert_eq!(root.find(&20).unwrap(), right);
+    // the regular check still guards the tree
+    assert!(!root.add_leaf(20));
+}
+
+#[test]
+fn unchecked_rejects_cycles_and_moves_children() {
+    let root = Node::new(1);
+    let child = Node::new(2);
+    let other = Node::new(3);
+    assert!(root.add_child_unchecked(&child));
+
+    assert!(!child.add_child_unchecked(&root));
+    assert!(!child.add_child_unchecked(&child));
+
+    assert!(other.add_child_unchecked(&child));
+    assert!(root.children().is_empty());
+    assert_eq!(child.parent().unwrap(), other);
+}
+
+#[test]
+fn policy_of_subtree_follows_root() {
+    let root = Node::with_policy(0, TreePolicy::AllowDuplicates);
+    let strict = Node::with_policy(1, TreePolicy::UniqueValues);
+    strict.add_leaf(2);
+    assert!(!strict.add_leaf(2));
+
+    assert!(root.add_child(&strict));
+    assert_eq!(strict.policy(), TreePolicy::AllowDuplicates);
+    assert!(strict.add_leaf(2));
+    assert_eq!(root.find_all(&2).len(), 2);
+}
+
+#[test]
+fn deep_clone_keeps_policy() {
+    let root = Node::with_policy(0, TreePolicy::AllowDuplicates);
+    root.add_leaf(0);
+
+    let copy = root.deep_clone();
+    assert_eq!(copy.policy(), TreePolicy::AllowDuplicates);
+    assert!(copy.add_leaf(0));
+}
+
+#[test]
+fn unique_tree_rejects_subtree_with_own_duplicates() {
+    let root = Node::new(0);
+    let loose = Node::with_policy(1, TreePolicy::AllowDuplicates);
+    loose.add_leaf(2);
+    loose.add_leaf(2);
+
+    assert_eq!(root.try_add_child(&loose), Err(AddError::DuplicateValue));
+    assert!(loose.parent().is_none());
+    assert_eq!(root.len(), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn indexed_unique_tree_rejects_subtree_with_own_duplicates() {
+    let root = Node::new(0);
+    let loose = Node::with_policy(1, TreePolicy::AllowDuplicates);
+    loose.add_leaf(2);
+    loose.add_leaf(2);
+
+    assert!(!root.add_child_indexed(&loose));
+    assert!(loose.parent().is_none());
+    assert!(root.add_child_indexed(&Node::new(3)));
+}
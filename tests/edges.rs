@@ -0,0 +1,39 @@
+#![cfg(feature = "std")]
+
+use a_ntree::{EdgeError, Node};
+
+#[test]
+fn edges_in_any_order() {
+    let rows = [(2, 5), (1, 3), (1, 2), (3, 6), (2, 4)];
+    let root = Node::from_edges(rows).unwrap();
+
+    assert_eq!(root.iter().map(|node| node.value()).collect::<Vec<_>>(), vec![1, 3, 6, 2, 5, 4]);
+    assert_eq!(root.validate(), Ok(()));
+}
+
+#[test]
+fn wide_and_deep_edge_lists() {
+    let wide = Node::from_edges((1..10_000).map(|child| (0, child))).unwrap();
+    assert_eq!(wide.children().len(), 9_999);
+
+    let deep = Node::from_edges((1..10_000).map(|child| (child - 1, child))).unwrap();
+    assert_eq!(deep.height(), 9_999);
+}
+
+#[test]
+fn invalid_edge_lists() {
+    assert_eq!(Node::<i32>::from_edges([]), Err(EdgeError::Empty));
+    assert_eq!(Node::from_edges([(1, 1)]), Err(EdgeError::NoRoot));
+    assert_eq!(Node::from_edges([(1, 2), (2, 1)]), Err(EdgeError::NoRoot));
+    assert_eq!(Node::from_edges([(0, 1), (2, 3), (3, 2)]), Err(EdgeError::Orphaned(vec![2, 3])));
+    assert_eq!(Node::from_edges([(0, 1), (5, 2), (6, 3)]), Err(EdgeError::MultipleRoots(vec![0, 5, 6])));
+    assert_eq!(Node::from_edges([(0, 1), (1, 2), (0, 2)]), Err(EdgeError::MultipleParents(2)));
+}
+
+#[test]
+fn edge_errors_are_displayable() {
+    let error = Node::from_edges([("a", "b"), ("c", "d")]).unwrap_err();
+
+    assert_eq!(error.to_string(), r#"the edges have more than one root: ["a", "c"]"#);
+    let _: &dyn std::error::Error = &error;
+}
@@ -0,0 +1,347 @@
+use a_ntree::{AddError, Node};
+
+fn five_children() -> Node<i32> {
+    let root = Node::new(0);
+    for value in 1..=5 {
+        root.add_leaf(value);
+    }
+    root
+}
+
+#[test]
+fn rotate_left_by_two() {
+    let root = five_children();
+    root.rotate_children_left(2);
+
+    assert_eq!(root.children_values(), vec![3, 4, 5, 1, 2]);
+    assert!(root.children().iter().all(|child| child.parent().unwrap() == root));
+}
+
+#[test]
+fn rotate_right_by_two() {
+    let root = five_children();
+    root.rotate_children_right(2);
+
+    assert_eq!(root.children_values(), vec![4, 5, 1, 2, 3]);
+}
+
+#[test]
+fn rotate_wraps_around() {
+    let root = five_children();
+    root.rotate_children_left(7);
+    assert_eq!(root.children_values(), vec![3, 4, 5, 1, 2]);
+
+    let leaf = Node::new(1);
+    leaf.rotate_children_right(3);
+    assert!(leaf.children().is_empty());
+}
+
+#[test]
+fn drained_children_become_roots() {
+    let root = five_children();
+    root.find(&3).unwrap().add_leaf(30);
+
+    let drained = root.drain_children();
+    assert!(root.children().is_empty());
+    assert_eq!(drained.iter().map(|child| child.value()).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    assert!(drained.iter().all(Node::is_root));
+    assert_eq!(drained[2].children_values(), vec![30]);
+    assert!(root.find(&30).is_none());
+
+    let other = Node::new(100);
+    assert!(other.add_child(&drained[2]));
+    assert_eq!(drained[2].get_root(), other);
+}
+
+#[test]
+fn insert_before_positions() {
+    let root = five_children();
+    let first = root.find(&1).unwrap();
+    let middle = root.find(&3).unwrap();
+    let last = root.find(&5).unwrap();
+
+    assert!(first.insert_before(&Node::new(10)));
+    assert!(middle.insert_before(&Node::new(30)));
+    assert!(last.insert_before(&Node::new(50)));
+
+    assert_eq!(root.children_values(), vec![10, 1, 2, 30, 3, 4, 50, 5]);
+    assert_eq!(root.find(&30).unwrap().parent().unwrap(), root);
+}
+
+#[test]
+fn insert_after_positions() {
+    let root = five_children();
+    let first = root.find(&1).unwrap();
+    let middle = root.find(&3).unwrap();
+    let last = root.find(&5).unwrap();
+
+    assert!(first.insert_after(&Node::new(10)));
+    assert!(middle.insert_after(&Node::new(30)));
+    assert!(last.insert_after(&Node::new(50)));
+
+    assert_eq!(root.children_values(), vec![1, 10, 2, 3, 30, 4, 5, 50]);
+    assert_eq!(root.find(&50).unwrap().parent().unwrap(), root);
+}
+
+#[test]
+fn insert_sibling_rejections() {
+    let root = five_children();
+    let middle = root.find(&3).unwrap();
+
+    assert!(!root.insert_before(&Node::new(10)));
+    assert!(!root.insert_after(&Node::new(10)));
+    assert!(!middle.insert_before(&Node::new(4)));
+    assert!(!middle.insert_after(&Node::new(0)));
+    assert_eq!(root.children_values(), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn split_off_subtree() {
+    let root = five_children();
+    let three = root.find(&3).unwrap();
+    let thirty = Node::new(30);
+    let _ = three.add_child(&thirty);
+    thirty.add_leaf(300);
+    three.add_leaf(31);
+    assert_eq!(root.len(), 9);
+
+    let split = three.split_off().unwrap();
+    assert_eq!(split, three);
+    assert_eq!(root.len(), 5);
+    assert_eq!(root.children_values(), vec![1, 2, 4, 5]);
+    assert!(root.find(&300).is_none());
+
+    assert!(split.is_root());
+    assert_eq!(split.len(), 4);
+    assert_eq!(thirty.get_root(), split);
+    assert!(split.add_leaf(1));
+    assert!(split.split_off().is_none());
+}
+
+#[test]
+fn retain_paths_to_value() {
+    //        0
+    //     /  |  \
+    //    1   2   3
+    //   / \      |
+    //  10  11    30
+    //  |
+    //  100
+    let root = Node::new(0);
+    let one = Node::new(1);
+    let three = Node::new(3);
+    let ten = Node::new(10);
+    let _ = root.add_child(&one);
+    root.add_leaf(2);
+    let _ = root.add_child(&three);
+    let _ = one.add_child(&ten);
+    one.add_leaf(11);
+    ten.add_leaf(100);
+    three.add_leaf(30);
+
+    root.retain_paths_to(|value| *value == 10 || *value == 30);
+
+    assert_eq!(root.children_values(), vec![1, 3]);
+    assert_eq!(one.children_values(), vec![10]);
+    assert!(ten.children().is_empty());
+    assert_eq!(three.children_values(), vec![30]);
+    assert_eq!(root.len(), 5);
+}
+
+#[test]
+fn retain_paths_without_matches() {
+    let root = five_children();
+    let removed = root.find(&3).unwrap();
+    root.retain_paths_to(|value| *value == 42);
+
+    assert!(root.children().is_empty());
+    assert!(removed.is_root());
+}
+
+#[test]
+fn edit_children_filter_and_reorder() {
+    let root = five_children();
+    let two = root.find(&2).unwrap();
+    let four = root.find(&4).unwrap();
+
+    let removed = root.edit_children(|children| {
+        children.retain(|child| child.value() % 2 == 1);
+        children.sort_by_key(|child| std::cmp::Reverse(child.value()));
+        5 - children.len()
+    });
+
+    assert_eq!(removed, 2);
+    assert_eq!(root.children_values(), vec![5, 3, 1]);
+    assert!(root.children().iter().all(|child| child.parent().unwrap() == root));
+    assert!(two.is_root());
+    assert!(four.is_root());
+}
+
+#[test]
+fn edit_children_moves_nodes_between_parents() {
+    let root = five_children();
+    let one = root.find(&1).unwrap();
+    let five = root.find(&5).unwrap();
+
+    one.edit_children(|children| children.push(root.find(&5).unwrap()));
+    one.edit_children(|children| children.push(root.find(&5).unwrap()));
+
+    assert_eq!(root.children_values(), vec![1, 2, 3, 4]);
+    assert_eq!(one.children_values(), vec![5]);
+    assert_eq!(five.parent().unwrap(), one);
+}
+
+#[test]
+#[should_panic(expected = "a node can't become a child of itself")]
+fn edit_children_rejects_cycles() {
+    let root = five_children();
+    let one = root.find(&1).unwrap();
+    one.edit_children(|children| children.push(root.get_root()));
+}
+
+#[test]
+fn move_multi_level_subtree() {
+    let root = five_children();
+    let two = root.find(&2).unwrap();
+    let twenty = Node::new(20);
+    let _ = two.add_child(&twenty);
+    twenty.add_leaf(200);
+    let four = root.find(&4).unwrap();
+
+    assert!(two.move_to(&four));
+    assert_eq!(root.children_values(), vec![1, 3, 4, 5]);
+    assert_eq!(four.children_values(), vec![2]);
+    assert_eq!(root.find(&200).unwrap().path_from_root().iter().map(Node::value).collect::<Vec<_>>(), vec![0, 4, 2, 20, 200]);
+    assert_eq!(root.len(), 8);
+}
+
+#[test]
+fn move_rejects_cycles() {
+    let root = five_children();
+    let one = root.find(&1).unwrap();
+    let ten = Node::new(10);
+    let _ = one.add_child(&ten);
+
+    assert!(!one.move_to(&one));
+    assert!(!one.move_to(&ten));
+    assert!(!root.move_to(&ten));
+    assert_eq!(one.parent().unwrap(), root);
+    assert_eq!(ten.parent().unwrap(), one);
+    assert_eq!(root.children_values(), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn move_respects_uniqueness_of_destination() {
+    let root = five_children();
+    let three = root.find(&3).unwrap();
+    let other = Node::new(3);
+
+    assert!(!three.move_to(&other));
+    assert_eq!(root.children_values(), vec![1, 2, 3, 4, 5]);
+    assert_eq!(three.parent().unwrap(), root);
+
+    let lone = Node::new(9);
+    assert!(lone.move_to(&three));
+    assert_eq!(lone.parent().unwrap(), three);
+}
+
+#[test]
+fn reparent_between_trees() {
+    let root = five_children();
+    let two = root.find(&2).unwrap();
+    two.add_leaf(20);
+    let other = Node::new(100);
+
+    assert_eq!(two.reparent(&other), Ok(()));
+    assert_eq!(root.len(), 5);
+    assert_eq!(other.find(&20).unwrap().get_root(), other);
+    assert_eq!(two.reparent(&Node::new(20)), Err(AddError::DuplicateValue));
+    assert_eq!(two.parent().unwrap(), other);
+}
+
+#[test]
+fn detach_keeps_descendants() {
+    let root = five_children();
+    let five = root.find(&5).unwrap();
+    five.add_leaf(50);
+
+    let detached = five.detach();
+    assert!(detached.is_root());
+    assert_eq!(root.children_values(), vec![1, 2, 3, 4]);
+    assert_eq!(detached.len(), 2);
+    assert!(root.add_child(&detached));
+}
+
+#[test]
+fn prune_drops_failing_subtrees() {
+    let root = five_children();
+    let two = root.find(&2).unwrap();
+    two.add_leaf(20);
+    two.add_leaf(21);
+    root.find(&3).unwrap().add_leaf(30);
+
+    root.prune(|value| value % 2 == 0 || *value == 3);
+    assert_eq!(root.children_values(), vec![2, 3, 4]);
+    assert_eq!(two.children_values(), vec![20]);
+    assert_eq!(root.len(), 6);
+    assert!(root.add_leaf(21));
+}
+
+#[test]
+fn insert_child_at_positions() {
+    let root = five_children();
+
+    assert!(root.insert_child_at(0, &Node::new(10)));
+    assert!(!root.insert_child_at(0, &Node::new(3)));
+    assert!(root.insert_child_at(6, &Node::new(6)));
+    assert!(!root.insert_child_at(8, &Node::new(8)));
+    assert_eq!(root.children_values(), vec![10, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(root.find(&6).unwrap().child_index(), Some(6));
+    assert_eq!(root.child_at(3).unwrap().value(), 3);
+}
+
+#[test]
+fn sort_children_descending() {
+    let root = five_children();
+    root.sort_children_by(|a, b| b.cmp(a));
+
+    assert_eq!(root.children_values(), vec![5, 4, 3, 2, 1]);
+    assert_eq!(root.find(&1).unwrap().child_index(), Some(4));
+}
+
+#[test]
+fn sort_children_is_stable() {
+    let root = Node::new((0, 'r')).allow_duplicates();
+    for child in [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')] {
+        root.add_leaf(child);
+    }
+    root.sort_children_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(root.children_values(), vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+}
+
+#[test]
+fn promote_keeps_position_and_order() {
+    let root = five_children();
+    let three = root.find(&3).unwrap();
+    three.add_leaf(30);
+    three.add_leaf(31);
+    root.find(&30).unwrap().add_leaf(300);
+
+    let removed = root.remove_node_promote(&3).unwrap();
+    assert!(removed.is_root());
+    assert_eq!(root.children_values(), vec![1, 2, 30, 31, 4, 5]);
+    assert_eq!(root.find(&300).unwrap().path(), vec![0, 30, 300]);
+    assert_eq!(root.find(&31).unwrap().parent().unwrap(), root);
+    assert!(root.add_leaf(3));
+}
+
+#[test]
+fn retain_children_detaches_dropped() {
+    let root = five_children();
+    let one = root.find(&1).unwrap();
+    root.retain_children(|value| *value > 2);
+
+    assert_eq!(root.children_values(), vec![3, 4, 5]);
+    assert!(one.is_root());
+}
@@ -0,0 +1,101 @@
+use a_ntree::{diff, tree, Edit, Node};
+
+fn assert_round_trip(old: &Node<i32>, new: &Node<i32>) -> Vec<Edit<i32>> {
+    let edits = diff(old, new);
+    let target = old.deep_clone();
+    assert_eq!(target.apply(&edits), Ok(()));
+    assert!(target.structural_eq(new), "{target} != {new}");
+    edits
+}
+
+#[test]
+fn equal_trees_need_no_edits() {
+    let old = tree! { 1 => [2 => [3], 4] };
+
+    assert!(assert_round_trip(&old, &old.deep_clone()).is_empty());
+}
+
+#[test]
+fn reordering_children_moves_them() {
+    let old = tree! { 1 => [2, 3, 4] };
+    let new = tree! { 1 => [4, 2, 3] };
+
+    assert_eq!(assert_round_trip(&old, &new), vec![Edit::Move { value: 4, parent: 1, index: 0 }]);
+}
+
+#[test]
+fn removing_a_subtree_is_one_edit() {
+    let old = tree! { 1 => [2 => [3 => [4]], 5] };
+    let new = tree! { 1 => [5] };
+
+    assert_eq!(assert_round_trip(&old, &new), vec![Edit::Remove { value: 2, parent: 1 }]);
+}
+
+#[test]
+fn kept_nodes_are_moved_out_of_removed_subtrees() {
+    let old = tree! { 1 => [2 => [3 => [4]]] };
+    let new = tree! { 1 => [4] };
+
+    assert_eq!(assert_round_trip(&old, &new), vec![
+        Edit::Move { value: 4, parent: 1, index: 0 },
+        Edit::Remove { value: 2, parent: 1 },
+    ]);
+}
+
+#[test]
+fn changed_root_is_an_update() {
+    let old = tree! { 1 => [2] };
+    let new = tree! { 10 => [2, 3] };
+
+    assert_eq!(assert_round_trip(&old, &new), vec![
+        Edit::Update { old: 1, new: 10 },
+        Edit::Insert { value: 3, parent: 10, index: 1 },
+    ]);
+}
+
+#[test]
+fn root_can_swap_values_with_descendants() {
+    assert_round_trip(&tree! { 1 => [2 => [3]] }, &tree! { 3 => [4 => [2 => [1]]] });
+    assert_round_trip(&tree! { 1 => [2 => [3], 4] }, &tree! { 2 => [1 => [4], 3] });
+    assert_round_trip(&tree! { 1 => [2 => [5, 6]] }, &tree! { 2 => [6] });
+}
+
+#[test]
+fn generated_trees_round_trip() {
+    let mut seed = 7_u64;
+    let mut next = |bound: usize| {
+        seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        usize::try_from(seed >> 33).unwrap() % bound
+    };
+    let mut random_tree = |values: &[i32]| {
+        let nodes: Vec<Node<i32>> = values.iter().map(|value| Node::new(*value)).collect();
+        for idx in 1..nodes.len() {
+            let parent = &nodes[next(idx)];
+            let index = next(parent.children().len() + 1);
+            assert!(parent.insert_child_at(index, &nodes[idx]));
+        }
+        nodes[0].get_root()
+    };
+
+    for _ in 0..50 {
+        let old = random_tree(&(0..30).collect::<Vec<_>>());
+        let new = random_tree(&(15..45).rev().collect::<Vec<_>>());
+        let shuffled = random_tree(&(0..30).rev().collect::<Vec<_>>());
+        assert_round_trip(&old, &shuffled);
+        assert_round_trip(&old, &new);
+        assert_round_trip(&new, &old);
+    }
+}
+
+#[test]
+fn apply_reports_edits_that_do_not_fit() {
+    let root = tree! { 1 => [2 => [3]] };
+
+    assert_eq!(root.apply(&[Edit::Update { old: 5, new: 6 }]), Err(0));
+    assert_eq!(root.apply(&[Edit::Insert { value: 4, parent: 9, index: 0 }]), Err(0));
+    assert_eq!(root.apply(&[Edit::Insert { value: 4, parent: 2, index: 2 }]), Err(0));
+    assert_eq!(root.apply(&[Edit::Move { value: 2, parent: 3, index: 0 }]), Err(0));
+    assert_eq!(root.apply(&[Edit::Move { value: 3, parent: 2, index: 1 }]), Err(0));
+    assert_eq!(root.apply(&[Edit::Remove { value: 3, parent: 1 }]), Err(0));
+    assert_eq!(root.render(a_ntree::RenderStyle::ascii()), tree! { 1 => [2 => [3]] }.render(a_ntree::RenderStyle::ascii()));
+}
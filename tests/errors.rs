@@ -0,0 +1,77 @@
+use a_ntree::{tree, AddError, InvariantError, Node, TreePolicy};
+
+#[test]
+fn child_of_another_tree_is_rejected() {
+    let first = Node::new(1);
+    let second = Node::new(2);
+    let child = Node::new(3);
+    let _ = first.add_child(&child);
+
+    assert_eq!(second.try_add_child(&child), Err(AddError::AlreadyHasParent));
+    assert_eq!(child.parent().unwrap(), first);
+    assert!(second.children().is_empty());
+}
+
+#[test]
+fn cycle_is_reported_before_duplicates() {
+    let root = Node::new(1).allow_duplicates();
+    let child = Node::new(1);
+    let _ = root.add_child(&child);
+
+    assert_eq!(child.try_add_child(&root), Err(AddError::WouldCreateCycle));
+    assert_eq!(child.try_add_child(&child), Err(AddError::WouldCreateCycle));
+}
+
+#[test]
+fn duplicate_in_subtree_of_child() {
+    let root = Node::new(1);
+    root.add_leaf(2);
+    let child = Node::new(3);
+    child.add_leaf(2);
+
+    assert_eq!(root.try_add_child(&child), Err(AddError::DuplicateValue));
+    assert_eq!(root.len(), 2);
+}
+
+#[test]
+fn errors_are_displayable() {
+    assert_eq!(AddError::AlreadyHasParent.to_string(), "the child already has a parent");
+}
+
+#[test]
+fn trees_stay_valid_through_edits() {
+    let root = tree! { 1 => [2 => [3, 4], 5 => [6]] };
+    let node = |value| root.find(&value).unwrap();
+
+    assert!(node(4).move_to(&node(6)));
+    assert!(node(2).insert_child_at(0, &Node::new(7)));
+    drop(root.remove_node_promote(&5));
+    root.retain_children(|value| *value != 2);
+    root.sort_children_by(|a, b| b.cmp(a));
+
+    assert_eq!(root.validate(), Ok(()));
+    assert_eq!(node(4).validate(), Ok(()));
+}
+
+#[test]
+fn children_of_dropped_parents_become_valid_roots() {
+    let parent = Node::new(1);
+    parent.add_leaf(2);
+    let child = parent.find(&2).unwrap();
+    drop(parent);
+
+    assert!(child.is_root());
+    assert_eq!(child.validate(), Ok(()));
+}
+
+#[test]
+fn duplicates_are_only_invalid_in_unique_trees() {
+    let unique = Node::new(1);
+    assert!(unique.add_child_unchecked(&Node::new(1)));
+    let duplicates = Node::with_policy(1, TreePolicy::AllowDuplicates);
+    duplicates.add_leaf(1);
+
+    assert_eq!(unique.validate(), Err(InvariantError::DuplicateValue));
+    assert_eq!(unique.children()[0].validate(), Ok(()));
+    assert_eq!(duplicates.validate(), Ok(()));
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "std")]
+
+use a_ntree::{IndexedTree, Node};
+
+#[test]
+fn bulk_insert_stays_unique() {
+    let mut tree = IndexedTree::new(0);
+    let root = tree.root().get_root();
+    for value in 1..100_000 {
+        assert!(tree.insert_leaf(&root, value));
+    }
+
+    assert_eq!(tree.len(), 100_000);
+    assert!(!tree.insert_leaf(&root, 50_000));
+    assert_eq!(tree.find(&99_999).unwrap().parent().unwrap(), root);
+}
+
+#[test]
+fn insert_rejects_duplicates_within_subtree() {
+    let mut tree = IndexedTree::new(0);
+    let root = tree.root().get_root();
+    let child = Node::new(1).allow_duplicates();
+    child.add_leaf(2);
+    child.add_leaf(2);
+
+    assert!(!tree.insert(&root, &child));
+    assert!(root.children().is_empty());
+    assert!(!tree.contains(&2));
+}
+
+#[test]
+fn insert_rejects_foreign_parent() {
+    let mut tree = IndexedTree::new(0);
+    let other = Node::new(10);
+
+    assert!(!tree.insert_leaf(&other, 1));
+    assert!(!tree.contains(&1));
+}
+
+#[test]
+fn remove_drops_subtree_from_index() {
+    let mut tree = IndexedTree::new(0);
+    let root = tree.root().get_root();
+    let child = Node::new(1);
+    child.add_leaf(2);
+    assert!(tree.insert(&root, &child));
+
+    assert_eq!(tree.remove(&1).unwrap(), child);
+    assert!(!tree.contains(&2));
+    assert_eq!(tree.len(), 1);
+    assert!(tree.insert_leaf(&root, 2));
+}
+
+#[test]
+fn stale_entries_after_direct_changes() {
+    let mut tree = IndexedTree::new(0);
+    let root = tree.root().get_root();
+    tree.insert_leaf(&root, 1);
+    root.find(&1).unwrap().set_value(2);
+
+    assert!(tree.find(&1).is_none());
+    assert!(tree.find(&2).is_none());
+    assert!(tree.reindex());
+    assert!(tree.find(&2).is_some());
+}
+
+#[test]
+fn from_root_detects_duplicates() {
+    let root = Node::new(0).allow_duplicates();
+    root.add_leaf(1);
+    assert!(IndexedTree::from_root(root.get_root()).is_some());
+
+    root.add_leaf(1);
+    assert!(IndexedTree::from_root(root).is_none());
+}
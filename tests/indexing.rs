@@ -0,0 +1,81 @@
+#![cfg(feature = "std")]
+
+use a_ntree::Node;
+
+#[test]
+fn builds_ten_thousand_nodes() {
+    let nodes: Vec<Node<u32>> = (0..10_000).map(Node::new).collect();
+    for (idx, node) in nodes.iter().enumerate().skip(1) {
+        assert!(nodes[(idx - 1) / 4].add_child_indexed(node));
+    }
+
+    let root = &nodes[0];
+    assert_eq!(root.len(), 10_000);
+    assert_eq!(nodes[9_999].get_root(), *root);
+    assert!(!nodes[42].add_leaf_indexed(9_999));
+}
+
+#[test]
+fn index_follows_other_changes() {
+    let root = Node::new(0);
+    for value in 1..=10 {
+        assert!(root.add_leaf_indexed(value));
+    }
+
+    root.remove_node(&5);
+    assert!(root.add_leaf_indexed(5));
+
+    root.find(&3).unwrap().set_value(30);
+    assert!(root.add_leaf_indexed(3));
+    assert!(!root.add_leaf_indexed(30));
+
+    assert!(root.add_leaf(11));
+    assert!(!root.add_leaf_indexed(11));
+
+    let drained = root.drain_children();
+    assert_eq!(drained.len(), 12);
+    assert!(root.add_leaf_indexed(11));
+}
+
+#[test]
+fn removals_keep_the_index_counts() {
+    let root = Node::new(0);
+    for value in 1..=3 {
+        assert!(root.add_leaf_indexed(value));
+    }
+    assert!(root.find(&1).unwrap().add_leaf_indexed(10));
+    assert!(root.find(&1).unwrap().add_leaf_indexed(11));
+
+    root.remove_node_promote(&1);
+    assert!(!root.add_leaf_indexed(10));
+    assert!(root.add_leaf_indexed(1));
+
+    root.prune(|value| *value != 10);
+    root.retain_children(|value| *value != 2);
+    assert!(root.add_leaf_indexed(10));
+    assert!(root.add_leaf_indexed(2));
+    assert!(!root.add_leaf_indexed(11));
+
+    let moved = root.find(&3).unwrap();
+    assert!(moved.move_to(&root.find(&11).unwrap()));
+    assert!(!root.add_leaf_indexed(3));
+    assert_eq!(root.len(), 6);
+}
+
+#[test]
+fn attached_subtrees_are_checked() {
+    let root = Node::new(0);
+    assert!(root.add_leaf_indexed(1));
+
+    let subtree = Node::new(2);
+    assert!(subtree.add_leaf_indexed(3));
+    assert!(subtree.add_leaf_indexed(1));
+    assert!(!root.add_child_indexed(&subtree));
+
+    let _ = subtree.remove_node(&1);
+    assert!(root.add_child_indexed(&subtree));
+    assert!(!subtree.add_leaf_indexed(1));
+    assert!(!root.add_leaf_indexed(3));
+    assert!(root.add_leaf_indexed(4));
+    assert_eq!(root.len(), 5);
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "json")]
+
+use a_ntree::{tree, AddError, JsonError, Node};
+
+#[test]
+fn round_trips_nested_trees() {
+    let root = tree! { 1 => [2 => [4, 5], 3] };
+    let parsed: Node<i32> = Node::from_json_str(&root.to_json_string()).unwrap();
+
+    assert!(parsed.structural_eq(&root));
+}
+
+#[test]
+fn round_trips_escaped_strings() {
+    let root = Node::new(String::from("say \"hi\"\n"));
+    root.add_leaf(String::from("tab\tback\\slash \u{1}"));
+    let parsed: Node<String> = Node::from_json_str(&root.to_json_string()).unwrap();
+
+    assert!(parsed.structural_eq(&root));
+}
+
+#[test]
+fn reads_unicode_escapes_and_whitespace() {
+    let json = "{ \"value\" : \"\\u00e9\\ud83c\\udf33\" ,\n \"children\" : [ ] }";
+    let root: Node<String> = Node::from_json_str(json).unwrap();
+
+    assert_eq!(root.value(), "é🌳");
+    assert!(root.is_leaf());
+}
+
+#[test]
+fn reads_deep_chains() {
+    // the uniqueness check makes longer chains slow, not the nesting
+    let depth = 5_000;
+    let json = (0..depth).map(|value| format!("{{\"value\":{value},\"children\":[")).collect::<String>() + &"]}".repeat(depth);
+    assert_eq!(Node::<usize>::from_json_str(&json).unwrap().height(), depth - 1);
+}
+
+#[test]
+fn reports_syntax_errors() {
+    let error = |json: &str| Node::<i32>::from_json_str(json).unwrap_err();
+
+    assert_eq!(error(""), JsonError::Syntax { position: 0 });
+    assert_eq!(error("{\"children\": [], \"value\": 1}"), JsonError::Syntax { position: 1 });
+    assert_eq!(error("{\"value\": 1, \"children\": [}"), JsonError::Syntax { position: 26 });
+    assert_eq!(error("{\"value\": 1, \"children\": [{\"value\": 2} {\"value\": 3}]}"), JsonError::Syntax { position: 39 });
+    assert_eq!(error("{\"value\": 1} trailing"), JsonError::Syntax { position: 13 });
+    assert_eq!(error("{\"value\": \"1"), JsonError::Syntax { position: 12 });
+    assert_eq!(error("{\"value\": \"\\x\"}"), JsonError::Syntax { position: 11 });
+}
+
+#[test]
+fn reports_rejected_children() {
+    let error = Node::<i32>::from_json_str("{\"value\": 1, \"children\": [{\"value\": 2}, {\"value\": 1}]}").unwrap_err();
+
+    assert_eq!(error, JsonError::Rejected { position: 40, error: AddError::DuplicateValue });
+    assert_eq!(error.to_string(), "node at byte 40 rejected: a value of the child is already in the tree");
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "std")]
+
+use std::hash::{Hash, Hasher};
+use a_ntree::{tree, AddError, MergeStrategy, Node, TraversalOrder};
+
+fn pre_order(root: &Node<i32>) -> Vec<i32> {
+    root.walk(TraversalOrder::PreOrder).map(|node| node.value()).collect()
+}
+
+#[test]
+fn merge_adds_new_root_below_self() {
+    let root = tree! { 1 => [2] };
+    let other = tree! { 10 => [11, 12] };
+    root.merge(&other, MergeStrategy::Skip).unwrap();
+
+    assert_eq!(pre_order(&root), vec![1, 2, 10, 11, 12]);
+    assert_eq!(pre_order(&other), vec![10, 11, 12]);
+    assert!(other.is_root());
+}
+
+#[test]
+fn merge_skip_keeps_existing_children() {
+    let root = tree! { 1 => [2 => [3], 4] };
+    root.merge(&tree! { 1 => [4 => [5], 6] }, MergeStrategy::Skip).unwrap();
+
+    assert_eq!(pre_order(&root), vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn merge_replace_swaps_subtree_in_place() {
+    let root = tree! { 1 => [2 => [3, 4], 5] };
+    root.merge(&tree! { 2 => [6 => [7]] }, MergeStrategy::Replace).unwrap();
+
+    assert_eq!(pre_order(&root), vec![1, 2, 6, 7, 5]);
+    assert_eq!(root.find(&2).unwrap().child_index(), Some(0));
+}
+
+#[test]
+fn merge_replace_moves_match_below_matched_parent() {
+    let root = tree! { 1 => [2 => [3], 9] };
+    root.merge(&tree! { 9 => [2] }, MergeStrategy::Replace).unwrap();
+
+    assert_eq!(pre_order(&root), vec![1, 9, 2]);
+    assert_eq!(root.find(&2).unwrap().parent().unwrap().value(), 9);
+    assert!(root.find(&3).is_none());
+}
+
+#[test]
+fn merge_replace_rebuilds_detached_values() {
+    let root = tree! { 1 => [2 => [3 => [4]]] };
+    root.merge(&tree! { 2 => [3] }, MergeStrategy::Replace).unwrap();
+
+    assert_eq!(pre_order(&root), vec![1, 2, 3]);
+    assert!(root.find(&3).unwrap().is_leaf());
+}
+
+#[test]
+fn merge_skip_keeps_matches_across_levels() {
+    let root = tree! { 1 => [2 => [3 => [4]], 5] };
+    root.merge(&tree! { 5 => [4 => [6]] }, MergeStrategy::Skip).unwrap();
+
+    assert_eq!(pre_order(&root), vec![1, 2, 3, 4, 6, 5]);
+    assert_eq!(root.find(&4).unwrap().parent().unwrap().value(), 3);
+    assert!(root.find(&5).unwrap().is_leaf());
+}
+
+#[test]
+fn merge_replace_keeps_ancestor_of_matched_parent() {
+    let root = tree! { 1 => [2 => [3]] };
+    let three = root.find(&3).unwrap();
+    three.merge(&tree! { 2 => [4] }, MergeStrategy::Replace).unwrap();
+
+    assert_eq!(pre_order(&root), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn merge_combine_calls_closure_on_collisions() {
+    #[derive(Debug, Clone)]
+    struct Entry(&'static str, u32);
+    impl PartialEq for Entry {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+    impl Eq for Entry {}
+    impl Hash for Entry {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.0.hash(state);
+        }
+    }
+
+    let root = Node::new(Entry("root", 1));
+    root.add_leaf(Entry("a", 2));
+    let other = Node::new(Entry("a", 5));
+    other.add_leaf(Entry("b", 1));
+
+    root.merge(&other, MergeStrategy::Combine(Box::new(|mine, theirs| Entry(mine.0, mine.1 + theirs.1)))).unwrap();
+    let a = root.find(&Entry("a", 0)).unwrap();
+    assert_eq!(a.borrow_value().1, 7);
+    assert_eq!(a.children_values(), vec![Entry("b", 1)]);
+}
+
+#[test]
+fn merge_combine_stops_on_collision() {
+    let root = tree! { 1 => [2, 3] };
+    let merged = root.merge(&tree! { 2 => [4] }, MergeStrategy::Combine(Box::new(|_, _| 3)));
+
+    assert_eq!(merged, Err(AddError::DuplicateValue));
+    assert_eq!(pre_order(&root), vec![1, 2, 3]);
+}
+
+#[test]
+fn merge_combine_matches_combined_value_later() {
+    let root = tree! { 1 => [2] };
+    root.merge(&tree! { 1 => [2 => [3]] }, MergeStrategy::Combine(Box::new(|mine, _| mine * 10))).unwrap();
+
+    assert_eq!(pre_order(&root), vec![10, 20, 3]);
+    root.merge(&tree! { 20 => [4] }, MergeStrategy::Skip).unwrap();
+    assert_eq!(root.find(&20).unwrap().children_values(), vec![3, 4]);
+}
+
+#[test]
+fn merge_with_itself_changes_nothing() {
+    let root = tree! { 1 => [2 => [3]] };
+    root.merge(&root.get_root(), MergeStrategy::Replace).unwrap();
+
+    assert_eq!(pre_order(&root), vec![1, 2, 3]);
+}
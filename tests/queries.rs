@@ -0,0 +1,205 @@
+use a_ntree::Node;
+
+#[test]
+fn count_at_depth_two() {
+    let root = Node::new(1);
+    let left = Node::new(2);
+    let right = Node::new(3);
+    let _ = root.add_child(&left);
+    let _ = root.add_child(&right);
+    left.add_leaf(4);
+    left.add_leaf(5);
+    right.add_leaf(6);
+    left.find(&4).unwrap().add_leaf(7);
+
+    assert_eq!(root.count_at_depth(1), 2);
+    assert_eq!(root.count_at_depth(2), 3);
+    assert_eq!(root.count_at_depth(3), 1);
+    assert_eq!(left.count_at_depth(2), 1);
+}
+
+#[test]
+fn depth_past_the_leaves_is_empty() {
+    let root = Node::new(1);
+    root.add_leaf(2);
+
+    assert_eq!(root.count_at_depth(0), 1);
+    assert_eq!(root.count_at_depth(2), 0);
+    assert_eq!(root.count_at_depth(usize::MAX), 0);
+    assert!(root.nodes_at_depth(usize::MAX).is_empty());
+}
+
+#[test]
+fn lone_root_depth_and_height() {
+    let root = Node::new(1);
+
+    assert_eq!(root.depth(), 0);
+    assert_eq!(root.height(), 0);
+}
+
+#[test]
+fn depth_and_height_in_tree() {
+    let root = Node::new(1);
+    let left = Node::new(2);
+    let deep = Node::new(4);
+    let _ = root.add_child(&left);
+    root.add_leaf(3);
+    let _ = left.add_child(&deep);
+    deep.add_leaf(5);
+
+    assert_eq!(deep.depth(), 2);
+    assert_eq!(root.find(&5).unwrap().depth(), 3);
+    assert_eq!(root.height(), 3);
+    assert_eq!(left.height(), 2);
+    assert_eq!(root.find(&3).unwrap().height(), 0);
+}
+
+#[test]
+fn height_after_remove() {
+    let root = Node::new(1);
+    let left = Node::new(2);
+    let _ = root.add_child(&left);
+    root.add_leaf(3);
+    left.add_leaf(4);
+    assert_eq!(root.height(), 2);
+
+    root.remove_node(&2);
+    assert_eq!(root.height(), 1);
+    assert_eq!(left.depth(), 0);
+    assert_eq!(left.height(), 1);
+}
+
+#[test]
+fn len_counts_subtree() {
+    let root = Node::new(1);
+    let left = Node::new(2);
+    let _ = root.add_child(&left);
+    root.add_leaf(3);
+    left.add_leaf(4);
+    left.add_leaf(5);
+
+    assert_eq!(root.len(), 5);
+    assert_eq!(left.len(), 3);
+    assert_eq!(root.find(&3).unwrap().len(), 1);
+
+    root.remove_node(&2);
+    assert_eq!(root.len(), 2);
+    assert_eq!(left.len(), 3);
+}
+
+#[test]
+fn siblings_in_order() {
+    let root = Node::new(0);
+    for value in [1, 2, 3] {
+        root.add_leaf(value);
+    }
+    let sibling_values = |value: i32| -> Vec<i32> {
+        root.find(&value).unwrap().siblings().iter().map(Node::value).collect()
+    };
+
+    assert_eq!(sibling_values(1), vec![2, 3]);
+    assert_eq!(sibling_values(2), vec![1, 3]);
+    assert_eq!(sibling_values(3), vec![1, 2]);
+    assert!(root.siblings().is_empty());
+}
+
+#[test]
+fn siblings_exclude_by_identity() {
+    let root = Node::new(0).allow_duplicates();
+    let first = Node::new(1);
+    let second = Node::new(1);
+    let _ = root.add_child(&first);
+    let _ = root.add_child(&second);
+
+    assert_eq!(first.siblings(), vec![second]);
+}
+
+#[test]
+fn leaves_of_three_levels() {
+    let root = Node::new(1);
+    let two = Node::new(2);
+    let three = Node::new(3);
+    let _ = root.add_child(&two);
+    let _ = root.add_child(&three);
+    root.add_leaf(4);
+    two.add_leaf(5);
+    two.add_leaf(6);
+    let seven = Node::new(7);
+    let _ = three.add_child(&seven);
+    seven.add_leaf(8);
+
+    let leaves: Vec<i32> = root.leaves().iter().map(Node::value).collect();
+    assert_eq!(leaves, vec![5, 6, 8, 4]);
+    assert_eq!(two.leaves().len(), 2);
+
+    let eight = root.find(&8).unwrap();
+    assert_eq!(eight.leaves(), vec![eight]);
+}
+
+/// ```text
+///        0
+///      /   \
+///     1     2
+///    / \    |
+///   3   4   5
+///           |
+///           6
+/// ```
+fn org_chart() -> Node<i32> {
+    let root = Node::new(0);
+    let one = Node::new(1);
+    let two = Node::new(2);
+    let five = Node::new(5);
+    let _ = root.add_child(&one);
+    let _ = root.add_child(&two);
+    one.add_leaf(3);
+    one.add_leaf(4);
+    let _ = two.add_child(&five);
+    five.add_leaf(6);
+    root
+}
+
+#[test]
+fn lowest_common_ancestor_at_different_depths() {
+    let root = org_chart();
+    let node = |value| root.find(&value).unwrap();
+
+    assert_eq!(node(3).lowest_common_ancestor(&node(4)).unwrap(), node(1));
+    assert_eq!(node(3).lowest_common_ancestor(&node(6)).unwrap(), root);
+    assert_eq!(node(6).lowest_common_ancestor(&node(2)).unwrap(), node(2));
+    assert_eq!(node(5).lowest_common_ancestor(&node(5)).unwrap(), node(5));
+}
+
+#[test]
+fn distances_in_org_chart() {
+    let root = org_chart();
+    let node = |value| root.find(&value).unwrap();
+
+    assert_eq!(node(3).distance_to(&node(6)), Some(5));
+    assert_eq!(node(6).distance_to(&node(3)), Some(5));
+    assert_eq!(node(6).distance_to(&root), Some(3));
+    assert_eq!(node(3).distance_to(&node(4)), Some(2));
+}
+
+#[test]
+fn ancestry_is_strict() {
+    let root = org_chart();
+    let six = root.find(&6).unwrap();
+
+    assert!(root.is_ancestor_of(&six));
+    assert!(six.is_descendant_of(&root));
+    assert!(!six.is_descendant_of(&six));
+    assert!(!root.find(&1).unwrap().is_ancestor_of(&six));
+}
+
+#[test]
+fn leaves_and_subtree_sizes() {
+    let root = org_chart();
+
+    for node in root.walk(a_ntree::TraversalOrder::PreOrder) {
+        assert_eq!(node.is_leaf(), node.children().is_empty());
+        assert_eq!(node.subtree_len(), 1 + node.children().iter().map(Node::subtree_len).sum::<usize>());
+    }
+    assert_eq!(root.leaves().iter().filter(|leaf| leaf.is_leaf()).count(), root.leaves().len());
+    assert_eq!(root.subtree_len(), root.len());
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "rayon")]
+
+use a_ntree::SyncNode;
+use rayon::iter::ParallelIterator;
+
+/// a root with `width` children, each with `width` leaves
+fn wide_tree(width: i32) -> SyncNode<i32> {
+    let root = SyncNode::new(-1);
+    for child in 0..width {
+        let node = SyncNode::new(child * (width + 1));
+        assert!(root.add_child(&node));
+        for leaf in 1..=width {
+            node.add_leaf(child * (width + 1) + leaf);
+        }
+    }
+    root
+}
+
+#[test]
+fn par_iter_yields_every_node_once() {
+    let root = wide_tree(40);
+    let mut values: Vec<i32> = root.par_iter().map(|node| *node.value()).collect();
+    values.sort_unstable();
+
+    assert_eq!(values, (-1..40 * 41).collect::<Vec<_>>());
+    assert_eq!(SyncNode::new(5).par_iter().count(), 1);
+}
+
+#[test]
+fn par_iter_on_a_subtree() {
+    let root = wide_tree(10);
+    let subtree = root.find(&11).unwrap();
+
+    assert_eq!(subtree.par_iter().count(), 11);
+    assert!(subtree.par_iter().all(|node| node == subtree || node.parent().unwrap() == subtree));
+}
+
+#[test]
+fn par_find_by_returns_first_in_pre_order() {
+    let root = wide_tree(50);
+    let found = root.par_find_by(|value| value % 7 == 3).unwrap();
+
+    assert_eq!(found.value(), &3);
+    assert_eq!(root.par_find_by(|value| *value == 2549).unwrap().parent().unwrap().value(), &2499);
+}
+
+#[test]
+fn par_map_keeps_structure() {
+    let root = wide_tree(20);
+    let mapped = root.par_map(|value| value.to_string());
+
+    let children = mapped.children();
+    assert_eq!(children.len(), 20);
+    assert_eq!(children[3].value(), "63");
+    assert_eq!(children[3].children().iter().map(|leaf| leaf.value().clone()).collect::<Vec<_>>(),
+               (64..=83).map(|value: i32| value.to_string()).collect::<Vec<_>>());
+    assert!(children.iter().all(|child| child.parent().unwrap() == mapped));
+}
@@ -1,4 +1,4 @@
-use a_ntree::Node;
+use a_ntree::{Node, WeakNode};
 
 #[test]
 fn adding() {
@@ -37,4 +37,78 @@ fn deleting() {
     assert_eq!(a.rc_count(), 2);
     root.remove_node(&20);
     assert_eq!(a.rc_count(), 1);
-}
\ No newline at end of file
+}
+
+#[test]
+fn removed_node_forgets_parent() {
+    let root = Node::new(10);
+    let child = Node::new(20);
+    let _ = root.add_child(&child);
+
+    let removed = root.remove_node(&20).unwrap();
+    assert_eq!(removed, child);
+    assert!(child.parent().is_none());
+    assert_eq!(root.rc_count(), 1);
+}
+
+#[test]
+fn deep_clone_is_independent() {
+    let root = Node::new(10);
+    let child = Node::new(20);
+    let _ = root.add_child(&child);
+    child.add_leaf(30);
+    child.add_leaf(40);
+
+    let copy = child.deep_clone();
+    assert_eq!(child.rc_count(), 2);
+    assert_eq!(copy.rc_count(), 1);
+    assert_eq!(copy.len(), child.len());
+    assert!(copy.is_root());
+
+    copy.find(&30).unwrap().set_value(31);
+    let _ = copy.remove_node(&40);
+    assert!(copy.add_leaf(10));
+    assert_eq!(child.children_values(), vec![30, 40]);
+    assert_eq!(copy.children_values(), vec![31, 10]);
+    assert_eq!(copy.find(&31).unwrap().parent().unwrap(), copy);
+}
+
+#[test]
+fn deep_clone_keeps_duplicates_policy() {
+    let root = Node::new(1).allow_duplicates();
+    let child = Node::new(1);
+    let _ = root.add_child(&child);
+    child.add_leaf(1);
+
+    let copy = child.deep_clone();
+    assert!(copy.allows_duplicates());
+    assert!(copy.add_leaf(1));
+}
+
+#[test]
+fn weak_nodes_do_not_keep_subtrees_alive() {
+    let root = Node::new(10);
+    let child = Node::new(20);
+    let _ = root.add_child(&child);
+    child.add_leaf(30);
+    let weak_child = child.downgrade();
+    let weak_leaf = root.find(&30).unwrap().downgrade();
+    drop(child);
+
+    assert_eq!(root.find(&20).unwrap().rc_count(), 2);
+    assert!(weak_leaf.upgrade().is_some());
+    drop(root.remove_node(&20));
+    assert!(weak_child.upgrade().is_none());
+    assert!(weak_leaf.upgrade().is_none());
+}
+
+#[test]
+fn weak_nodes_compare_by_identity() {
+    let node = Node::new(10);
+    let weak = node.downgrade();
+
+    assert_eq!(weak, node.downgrade());
+    assert_eq!(weak.clone(), weak);
+    assert_ne!(weak, Node::new(10).downgrade());
+    assert!(WeakNode::<i32>::default().upgrade().is_none());
+}
@@ -0,0 +1,132 @@
+use a_ntree::Node;
+
+fn values(nodes: &[Node<&'static str>]) -> Vec<&'static str> {
+    nodes.iter().map(|node| node.value()).collect()
+}
+
+fn three_levels() -> Node<&'static str> {
+    let root = Node::new("/");
+    let usr = Node::new("usr");
+    let etc = Node::new("etc");
+    let _ = root.add_child(&usr);
+    let _ = root.add_child(&etc);
+    usr.add_leaf("bin");
+    usr.add_leaf("lib");
+    etc.add_leaf("hosts");
+    root
+}
+
+#[test]
+fn find_path_three_levels() {
+    let root = three_levels();
+
+    assert_eq!(values(&root.find_path(&"lib").unwrap()), vec!["/", "usr", "lib"]);
+    assert_eq!(values(&root.find_path(&"hosts").unwrap()), vec!["/", "etc", "hosts"]);
+    assert_eq!(values(&root.find_path(&"etc").unwrap()), vec!["/", "etc"]);
+    assert_eq!(values(&root.find_path(&"/").unwrap()), vec!["/"]);
+    assert!(root.find_path(&"var").is_none());
+}
+
+#[test]
+fn find_path_from_subtree() {
+    let root = three_levels();
+    let usr = root.find(&"usr").unwrap();
+
+    assert_eq!(values(&usr.find_path(&"bin").unwrap()), vec!["usr", "bin"]);
+    assert!(usr.find_path(&"hosts").is_none());
+}
+
+#[test]
+fn contains_node_by_identity() {
+    let root = three_levels();
+    let lib = root.find(&"lib").unwrap();
+    let other_lib = Node::new("lib");
+
+    assert!(root.subtree_contains_node(&lib));
+    assert!(root.subtree_contains_node(&root));
+    assert!(!root.subtree_contains_node(&other_lib));
+    assert!(!root.find(&"etc").unwrap().subtree_contains_node(&lib));
+}
+
+#[test]
+fn contains_node_with_equal_values_in_one_tree() {
+    let root = Node::new("x").allow_duplicates();
+    let left = Node::new("x");
+    let right = Node::new("x");
+    let _ = root.add_child(&left);
+    let _ = root.add_child(&right);
+    let deep = Node::new("x");
+    let _ = left.add_child(&deep);
+
+    assert!(left.subtree_contains_node(&deep));
+    assert!(!right.subtree_contains_node(&deep));
+    assert!(!right.subtree_contains_node(&left));
+    assert!(root.subtree_contains_node(&right));
+}
+
+#[test]
+fn find_all_in_pre_order() {
+    let root = Node::new("x").allow_duplicates();
+    let left = Node::new("a");
+    let right = Node::new("x");
+    let _ = root.add_child(&left);
+    let _ = root.add_child(&right);
+    left.add_leaf("x");
+    right.add_leaf("x");
+
+    let found = root.find_all(&"x");
+    assert_eq!(found.len(), 4);
+    assert_eq!(found[0], root);
+    assert_eq!(found[1].parent().unwrap(), left);
+    assert_eq!(found[2], right);
+    assert_eq!(found[3].parent().unwrap(), right);
+    assert_eq!(root.find(&"x").unwrap(), found[0]);
+    assert_eq!(left.find_all(&"x").len(), 1);
+}
+
+#[test]
+fn find_all_unique_tree() {
+    let root = three_levels();
+
+    assert_eq!(values(&root.find_all(&"bin")), vec!["bin"]);
+    assert!(root.find_all(&"var").is_empty());
+}
+
+#[test]
+fn find_by_substring() {
+    let root = Node::new(String::from("src"));
+    let module = Node::new(String::from("src/tree"));
+    let _ = root.add_child(&module);
+    module.add_leaf(String::from("src/tree/node.rs"));
+    module.add_leaf(String::from("src/tree/iter.rs"));
+    root.add_leaf(String::from("src/lib.rs"));
+
+    let iter = root.find_by(|path| path.contains("iter")).unwrap();
+    assert_eq!(iter.value(), "src/tree/iter.rs");
+    assert_eq!(iter.parent().unwrap(), module);
+    assert!(root.find_by(|path| path.contains("main")).is_none());
+
+    let rust_files: Vec<String> = root.find_all_by(|path| path.ends_with(".rs")).iter().map(Node::value).collect();
+    assert_eq!(rust_files, vec!["src/tree/node.rs", "src/tree/iter.rs", "src/lib.rs"]);
+}
+
+#[test]
+fn index_path_round_trip() {
+    let root = three_levels();
+    for node in root.iter_dfs() {
+        let path = node.index_path();
+        assert_eq!(path.len(), node.depth());
+        assert_eq!(root.node_at_index_path(&path).unwrap(), node);
+    }
+}
+
+#[test]
+fn index_path_relative_to_subtree() {
+    let root = three_levels();
+    let leaf = root.leaves().pop().unwrap();
+    let parent = leaf.parent().unwrap();
+
+    assert_eq!(parent.node_at_index_path(&leaf.index_path()[parent.depth()..]).unwrap(), leaf);
+    assert_eq!(leaf.path(), vec!["/", "etc", "hosts"]);
+    assert!(root.node_at_index_path(&[99]).is_none());
+}
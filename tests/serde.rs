@@ -0,0 +1,54 @@
+#![cfg(feature = "serde")]
+
+use a_ntree::Node;
+
+fn sample() -> Node<String> {
+    let root = Node::new(String::from("root"));
+    let child = Node::new(String::from("child"));
+    let _ = root.add_child(&child);
+    child.add_leaf(String::from("grandchild"));
+    root.add_leaf(String::from("leaf"));
+    root
+}
+
+#[test]
+fn serializes_nested_values() {
+    let json = serde_json::to_string(&sample()).unwrap();
+
+    assert_eq!(json, concat!(
+        r#"{"value":"root","children":["#,
+        r#"{"value":"child","children":[{"value":"grandchild","children":[]}]},"#,
+        r#"{"value":"leaf","children":[]}"#,
+        "]}",
+    ));
+}
+
+#[test]
+fn round_trip_rebuilds_links() {
+    let original = sample();
+    let json = serde_json::to_string(&original).unwrap();
+    let loaded: Node<String> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+    assert_eq!(loaded.len(), original.len());
+    let grandchild = loaded.find(&String::from("grandchild")).unwrap();
+    assert_eq!(grandchild.parent().unwrap().value(), "child");
+    assert_eq!(grandchild.get_root(), loaded);
+    assert!(loaded.is_root());
+}
+
+#[test]
+fn missing_children_mean_leaf() {
+    let loaded: Node<i32> = serde_json::from_str(r#"{"value":1,"children":[{"value":2}]}"#).unwrap();
+
+    assert_eq!(loaded.children_values(), vec![2]);
+}
+
+#[test]
+fn duplicate_values_are_rejected() {
+    let result = serde_json::from_str::<Node<i32>>(
+        r#"{"value":1,"children":[{"value":2,"children":[{"value":1}]}]}"#,
+    );
+
+    assert!(result.unwrap_err().to_string().contains("duplicate value"));
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "std")]
+
+use std::thread;
+use a_ntree::SyncNode;
+
+#[test]
+fn sync_node_is_send_and_sync() {
+    fn assert_send_sync<S: Send + Sync>() {}
+    assert_send_sync::<SyncNode<i32>>();
+}
+
+#[test]
+fn concurrent_adds_keep_values_unique() {
+    let root = SyncNode::new(-1);
+
+    thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                for value in 0..50 {
+                    root.add_leaf(value);
+                }
+            });
+        }
+    });
+
+    let mut values: Vec<i32> = root.children().iter().map(|child| *child.value()).collect();
+    values.sort_unstable();
+    assert_eq!(values, (0..50).collect::<Vec<_>>());
+}
+
+#[test]
+fn concurrent_adds_into_subtrees() {
+    let root = SyncNode::new(0);
+    let branches: Vec<SyncNode<i32>> = (1..=4).map(SyncNode::new).collect();
+    for branch in &branches {
+        assert!(root.add_child(branch));
+    }
+
+    thread::scope(|scope| {
+        for branch in &branches {
+            let root = &root;
+            scope.spawn(move || {
+                // every thread also tries the values of the other threads
+                for value in 10..30 {
+                    branch.add_leaf(value);
+                }
+                assert!(root.find(&10).is_some());
+            });
+        }
+    });
+
+    let total: usize = branches.iter().map(|branch| branch.children().len()).sum();
+    assert_eq!(total, 20);
+    for value in 10..30 {
+        let found = root.find(&value).unwrap();
+        assert_eq!(found.get_root(), root);
+    }
+}
+
+#[test]
+fn concurrent_attach_and_remove() {
+    let root = SyncNode::new(0);
+    let subtrees: Vec<SyncNode<i32>> = (1..=8).map(|i| {
+        let subtree = SyncNode::new(i * 100);
+        subtree.add_leaf(i * 100 + 1);
+        subtree
+    }).collect();
+
+    thread::scope(|scope| {
+        for subtree in &subtrees {
+            let root = &root;
+            scope.spawn(move || {
+                assert!(root.add_child(subtree));
+                let removed = root.remove_node(&(subtree.value() + 1)).unwrap();
+                assert!(removed.parent().is_none());
+            });
+        }
+    });
+
+    assert_eq!(root.children().len(), 8);
+    assert!(subtrees.iter().all(|subtree| subtree.children().is_empty()));
+}
+
+#[test]
+fn attaching_a_tree_below_itself_fails() {
+    let root = SyncNode::new(0);
+    let child = SyncNode::new(1);
+    assert!(root.add_child(&child));
+
+    assert!(!child.add_child(&root));
+    assert!(!root.add_child(&root));
+    assert_eq!(root.remove_node(&1).unwrap(), child);
+    assert!(!child.add_child(&child));
+}
+
+#[test]
+fn sync_module_node_builds_and_queries_from_threads() {
+    use a_ntree::sync::Node;
+
+    let root = Node::new(String::from("root"));
+    thread::scope(|scope| {
+        for branch in ["a", "b", "c"] {
+            let root = &root;
+            scope.spawn(move || {
+                let node = Node::new(String::from(branch));
+                for leaf in 0..10 {
+                    node.add_leaf(format!("{branch}{leaf}"));
+                }
+                assert!(root.add_child(&node));
+            });
+        }
+    });
+
+    thread::scope(|scope| {
+        for branch in ["a", "b", "c"] {
+            let root = &root;
+            scope.spawn(move || {
+                let leaf = root.find(&format!("{branch}9")).unwrap();
+                assert_eq!(leaf.parent().unwrap().value(), branch);
+                assert_eq!(leaf.get_root(), *root);
+            });
+        }
+    });
+    assert_eq!(root.children().len(), 3);
+}
@@ -0,0 +1,262 @@
+use a_ntree::{Node, TraversalOrder, WalkControl};
+
+/// ```text
+///         1
+///       /   \
+///      2     3
+///     / \    |
+///    4   5   6
+///            |
+///            7
+/// ```
+fn sample_tree() -> Node<i32> {
+    let root = Node::new(1);
+    let two = Node::new(2);
+    let three = Node::new(3);
+    let six = Node::new(6);
+    let _ = root.add_child(&two);
+    let _ = root.add_child(&three);
+    two.add_leaf(4);
+    two.add_leaf(5);
+    let _ = three.add_child(&six);
+    six.add_leaf(7);
+    root
+}
+
+#[test]
+fn postorder_visits_leaves_first() {
+    let root = sample_tree();
+    let mut visited = vec![];
+    root.apply_postorder(|node| visited.push(node.value()));
+
+    assert_eq!(visited, vec![4, 5, 2, 7, 6, 3, 1]);
+}
+
+#[test]
+fn postorder_on_subtree() {
+    let root = sample_tree();
+    let mut visited = vec![];
+    root.find(&3).unwrap().apply_postorder(|node| visited.push(node.value()));
+
+    assert_eq!(visited, vec![7, 6, 3]);
+}
+
+fn walked(root: &Node<i32>, order: TraversalOrder) -> Vec<i32> {
+    root.walk(order).map(|node| node.value()).collect()
+}
+
+#[test]
+fn walk_pre_order() {
+    assert_eq!(walked(&sample_tree(), TraversalOrder::PreOrder), vec![1, 2, 4, 5, 3, 6, 7]);
+}
+
+#[test]
+fn walk_post_order() {
+    assert_eq!(walked(&sample_tree(), TraversalOrder::PostOrder), vec![4, 5, 2, 7, 6, 3, 1]);
+}
+
+#[test]
+fn walk_breadth_first() {
+    assert_eq!(walked(&sample_tree(), TraversalOrder::BreadthFirst), vec![1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn walk_single_node() {
+    let leaf = Node::new(1);
+    for order in [TraversalOrder::PreOrder, TraversalOrder::PostOrder, TraversalOrder::BreadthFirst] {
+        assert_eq!(walked(&leaf, order), vec![1]);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Num(i32),
+    Add,
+    Mul,
+}
+
+#[test]
+fn fold_evaluates_expression() {
+    // (2 + 3) * 4
+    let mul = Node::new(Token::Mul);
+    let add = Node::new(Token::Add);
+    let _ = mul.add_child(&add);
+    add.add_leaf(Token::Num(2));
+    add.add_leaf(Token::Num(3));
+    mul.add_leaf(Token::Num(4));
+
+    let result = mul.fold_tree(
+        |token| match token {
+            Token::Num(n) => *n,
+            _ => panic!("operators need operands"),
+        },
+        |token, operands: Vec<i32>| match token {
+            Token::Add => operands.iter().sum(),
+            Token::Mul => operands.iter().product(),
+            Token::Num(_) => panic!("numbers are leaves"),
+        },
+    );
+    assert_eq!(result, 20);
+}
+
+#[test]
+fn fold_counts_depth() {
+    let height = sample_tree().fold_tree(|_| 0, |_, children: Vec<usize>| 1 + children.into_iter().max().unwrap());
+    assert_eq!(height, 3);
+}
+
+#[test]
+fn iter_dfs_order() {
+    let values: Vec<i32> = sample_tree().iter_dfs().map(|node| node.value()).collect();
+    assert_eq!(values, vec![1, 2, 4, 5, 3, 6, 7]);
+}
+
+#[test]
+fn iter_bfs_order() {
+    let values: Vec<i32> = sample_tree().iter_bfs().map(|node| node.value()).collect();
+    assert_eq!(values, vec![1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn iterators_are_lazy() {
+    let root = sample_tree();
+    let first_two: Vec<i32> = root.iter_dfs().take(2).map(|node| node.value()).collect();
+    assert_eq!(first_two, vec![1, 2]);
+    assert_eq!(root.iter_bfs().find(|node| node.value() == 6).unwrap().parent().unwrap().value(), 3);
+}
+
+#[test]
+fn iterators_survive_removal() {
+    let root = sample_tree();
+    let mut dfs = root.iter_dfs();
+    let mut bfs = root.iter_bfs();
+    assert_eq!(dfs.next().unwrap().value(), 1);
+    assert_eq!(bfs.next().unwrap().value(), 1);
+
+    // 3 already sits on both work lists, its children are looked up when it is reached
+    root.remove_node(&6);
+    root.remove_node(&4);
+    assert_eq!(dfs.map(|node| node.value()).collect::<Vec<_>>(), vec![2, 5, 3]);
+    assert_eq!(bfs.map(|node| node.value()).collect::<Vec<_>>(), vec![2, 3, 5]);
+}
+
+#[test]
+fn ancestors_up_to_root() {
+    let root = sample_tree();
+    let seven = root.find(&7).unwrap();
+
+    assert_eq!(seven.ancestors().map(|node| node.value()).collect::<Vec<_>>(), vec![6, 3, 1]);
+    assert_eq!(seven.ancestors().take_while(|node| node.value() != 3).count(), 1);
+    assert_eq!(seven.ancestors().last().unwrap(), root);
+    assert!(root.ancestors().next().is_none());
+}
+
+#[test]
+fn path_from_root_for_breadcrumbs() {
+    let root = sample_tree();
+    let breadcrumbs: Vec<i32> = root.find(&5).unwrap().path_from_root().iter().map(Node::value).collect();
+
+    assert_eq!(breadcrumbs, vec![1, 2, 5]);
+    assert_eq!(root.path_from_root(), vec![root]);
+}
+
+#[test]
+fn iter_dfs_post_order() {
+    let values: Vec<i32> = sample_tree().iter_dfs_post().map(|node| node.value()).collect();
+    assert_eq!(values, vec![4, 5, 2, 7, 6, 3, 1]);
+}
+
+#[test]
+fn size_hint_counts_pending_nodes() {
+    let root = sample_tree();
+    let mut dfs = root.iter_dfs();
+    assert_eq!(dfs.size_hint(), (1, None));
+    dfs.next();
+    assert_eq!(dfs.size_hint(), (2, None));
+
+    let mut bfs = root.iter_bfs();
+    bfs.next();
+    bfs.next();
+    assert_eq!(bfs.size_hint(), (3, None));
+
+    let mut post = root.iter_dfs_post();
+    assert_eq!(post.size_hint(), (1, None));
+    post.next();
+    assert_eq!(post.size_hint(), (2, None));
+    assert_eq!(post.count(), 6);
+
+    let seven = root.find(&7).unwrap();
+    assert_eq!(seven.ancestors().size_hint(), (1, None));
+    assert_eq!(root.ancestors().size_hint(), (0, None));
+}
+
+#[test]
+fn descendants_skip_start() {
+    let root = sample_tree();
+    let values: Vec<i32> = root.descendants().map(|node| node.value()).collect();
+    assert_eq!(values, vec![2, 4, 5, 3, 6, 7]);
+
+    let three = root.find(&3).unwrap();
+    assert_eq!(three.descendants().count(), 2);
+    assert_eq!(three.descendants().size_hint(), (1, None));
+}
+
+#[test]
+fn step_through_siblings() {
+    let root = sample_tree();
+    let four = root.find(&4).unwrap();
+    let five = four.next_sibling().unwrap();
+
+    assert_eq!(five.value(), 5);
+    assert_eq!(five.prev_sibling().unwrap(), four);
+    assert!(five.next_sibling().is_none());
+    assert!(root.next_sibling().is_none());
+    assert!(root.prev_sibling().is_none());
+}
+
+#[test]
+fn map_keeps_shape() {
+    let root = sample_tree();
+    let doubled = root.map(|value| value * 2);
+
+    assert!(doubled.is_root());
+    assert_eq!(doubled.iter_dfs().map(|node| node.value()).collect::<Vec<_>>(), vec![2, 4, 8, 10, 6, 12, 14]);
+    assert!(doubled.map(|value| value / 2).structural_eq(&root));
+}
+
+#[test]
+fn fold_sums_subtree() {
+    let root = sample_tree();
+    assert_eq!(root.fold(|value, children: Vec<i32>| value + children.iter().sum::<i32>()), 28);
+    assert_eq!(root.find(&6).unwrap().fold(|value, children: Vec<i32>| value + children.iter().sum::<i32>()), 13);
+}
+
+#[test]
+fn visit_reports_depth() {
+    let mut visited = vec![];
+    sample_tree().visit(|node, depth| {
+        visited.push((node.value(), depth));
+        WalkControl::Continue
+    });
+    assert_eq!(visited, vec![(1, 0), (2, 1), (4, 2), (5, 2), (3, 1), (6, 2), (7, 3)]);
+}
+
+#[test]
+fn visit_stops_early() {
+    let mut visited = vec![];
+    sample_tree().visit(|node, _| {
+        visited.push(node.value());
+        if node.value() == 5 { WalkControl::Stop } else { WalkControl::Continue }
+    });
+    assert_eq!(visited, vec![1, 2, 4, 5]);
+}
+
+#[test]
+fn visit_skips_subtrees() {
+    let mut visited = vec![];
+    sample_tree().visit(|node, _| {
+        visited.push(node.value());
+        if node.value() == 2 { WalkControl::SkipSubtree } else { WalkControl::Continue }
+    });
+    assert_eq!(visited, vec![1, 2, 3, 6, 7]);
+}
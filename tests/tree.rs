@@ -0,0 +1,141 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use a_ntree::{Node, Tree, TreeEvent};
+
+#[test]
+fn len_follows_changes_through_tree() {
+    let mut tree = Tree::from_root(Node::new(0));
+    let root = tree.root().unwrap().get_root();
+    let child = Node::new(1);
+    child.add_leaf(2);
+    child.add_leaf(3);
+
+    assert!(tree.insert(&root, &child));
+    assert_eq!(tree.len(), 4);
+    assert!(tree.insert_leaf(&child, 4));
+    assert_eq!(tree.len(), 5);
+    assert!(!tree.insert_leaf(&root, 4));
+
+    assert_eq!(tree.remove(&1).unwrap(), child);
+    assert_eq!(tree.len(), 1);
+    assert!(tree.remove(&1).is_none());
+    assert_eq!(tree.len(), root.len());
+}
+
+#[test]
+fn insert_rejects_parent_of_another_tree() {
+    let mut tree = Tree::from_root(Node::new(0));
+    let other = Node::new(10);
+
+    assert!(!tree.insert(&other, &Node::new(1)));
+    assert!(other.children().is_empty());
+    assert_eq!(tree.len(), 1);
+}
+
+#[test]
+fn empty_tree() {
+    let mut tree = Tree::default();
+    let orphan = Node::new(1);
+
+    assert!(!tree.insert(&orphan, &Node::new(2)));
+    assert_eq!(tree.height(), 0);
+    assert!(tree.find(&1).is_none());
+    assert!(tree.remove(&1).is_none());
+
+    assert!(tree.set_root(orphan).is_none());
+    assert!(!tree.is_empty());
+    assert_eq!(tree.len(), 1);
+}
+
+#[test]
+fn set_root_detaches_subtree() {
+    let root = Node::new(0);
+    let child = Node::new(1);
+    let _ = root.add_child(&child);
+    child.add_leaf(2);
+
+    let tree = Tree::from_root(root.find(&1).unwrap());
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree.height(), 1);
+    assert!(child.is_root());
+    assert!(root.children().is_empty());
+}
+
+fn record(tree: &mut Tree<i32>) -> Rc<RefCell<Vec<String>>> {
+    let log = Rc::new(RefCell::new(vec![]));
+    let events = Rc::clone(&log);
+    tree.subscribe(move |event| {
+        events.borrow_mut().push(match event {
+            TreeEvent::ChildAdded { parent, child } => format!("added {} below {}", child.value(), parent.value()),
+            TreeEvent::NodeRemoved { parent, node } => format!("removed {} from {}", node.value(), parent.value()),
+            TreeEvent::ValueChanged { node, old } => format!("changed {old} to {}", node.value()),
+            TreeEvent::Reparented { node, old_parent } => {
+                format!("moved {} from {} to {}", node.value(), old_parent.value(), node.parent().unwrap().value())
+            }
+            TreeEvent::RootChanged { old } => format!("root {:?} replaced", old.map(Node::value)),
+        });
+    });
+    log
+}
+
+#[test]
+fn observers_see_every_change_made_through_the_tree() {
+    let mut tree = Tree::new();
+    let log = record(&mut tree);
+    tree.set_root(Node::new(1));
+    let root = tree.root().unwrap().get_root();
+
+    assert!(tree.insert_leaf(&root, 2));
+    assert!(tree.insert_leaf(&root, 3));
+    assert!(!tree.insert_leaf(&root, 3));
+    assert!(tree.move_node(&tree.find(&3).unwrap(), &tree.find(&2).unwrap()));
+    assert_eq!(tree.set_value(&tree.find(&3).unwrap(), 4), Ok(3));
+    assert!(tree.remove(&2).is_some());
+    assert!(tree.remove(&1).is_some());
+
+    assert_eq!(*log.borrow(), vec![
+        "root None replaced",
+        "added 2 below 1",
+        "added 3 below 1",
+        "moved 3 from 1 to 2",
+        "changed 3 to 4",
+        "removed 2 from 1",
+        "root Some(1) replaced",
+    ]);
+}
+
+#[test]
+fn rejected_changes_are_not_reported() {
+    let mut tree = Tree::from_root(Node::new(1));
+    let root = tree.root().unwrap().get_root();
+    tree.insert_leaf(&root, 2);
+    let log = record(&mut tree);
+    let two = tree.find(&2).unwrap();
+
+    assert!(!tree.move_node(&root, &two));
+    assert!(!tree.move_node(&two, &Node::new(5)));
+    assert_eq!(tree.set_value(&two, 1), Err(1));
+    assert!(!tree.insert_leaf(&Node::new(5), 6));
+    assert!(tree.remove(&7).is_none());
+    two.add_leaf(3);
+
+    assert!(log.borrow().is_empty());
+}
+
+#[test]
+fn observers_are_called_in_order_until_unsubscribed() {
+    let mut tree = Tree::from_root(Node::new(0));
+    let root = tree.root().unwrap().get_root();
+    let calls = Rc::new(RefCell::new(vec![]));
+    let ids: Vec<_> = (0..3).map(|observer| {
+        let calls = Rc::clone(&calls);
+        tree.subscribe(move |_| calls.borrow_mut().push(observer))
+    }).collect();
+
+    tree.insert_leaf(&root, 1);
+    assert!(tree.unsubscribe(ids[1]));
+    assert!(!tree.unsubscribe(ids[1]));
+    tree.insert_leaf(&root, 2);
+
+    assert_eq!(*calls.borrow(), vec![0, 1, 2, 0, 2]);
+}
@@ -0,0 +1,73 @@
+use a_ntree::{Node, TreePolicy};
+
+#[test]
+fn uniqueness_follows_mutated_values() {
+    let root = Node::new(1);
+    let child = Node::new(2);
+    let _ = root.add_child(&child);
+
+    child.set_value(3);
+    assert!(root.add_leaf(2));
+    assert!(!root.add_leaf(3));
+
+    child.with_value_mut(|value| *value *= 10);
+    assert!(root.add_leaf(3));
+    assert!(!root.add_leaf(30));
+    assert_eq!(root.find(&30).unwrap(), child);
+}
+
+#[test]
+fn borrow_value_reads_in_place() {
+    let node = Node::new(vec![1, 2, 3]);
+    node.with_value_mut(|value| value.push(4));
+
+    assert_eq!(node.borrow_value().len(), 4);
+    assert_eq!(node.borrow_value()[3], 4);
+}
+
+#[test]
+#[should_panic(expected = "already borrowed")]
+fn mutating_while_borrowed_panics() {
+    let node = Node::new(1);
+    let _guard = node.borrow_value();
+    node.set_value(2);
+}
+
+#[test]
+fn checked_changes_keep_values_unique() {
+    let root = Node::new(1);
+    let child = Node::new(2);
+    let _ = root.add_child(&child);
+    child.add_leaf(3);
+
+    assert_eq!(root.try_set_value(3), Err(3));
+    assert!(!child.update(|value| *value = 1));
+    assert_eq!(root.children_values(), vec![2]);
+    assert_eq!(root.try_set_value(4), Ok(1));
+    assert!(child.update(|value| *value = 1));
+    assert_eq!(root.find(&1).unwrap(), child);
+}
+
+#[test]
+fn checked_changes_follow_policy() {
+    let root = Node::with_policy(1, TreePolicy::AllowDuplicates);
+    root.add_leaf(2);
+
+    assert_eq!(root.try_set_value(2), Ok(1));
+    assert_eq!(root.find_all(&2).len(), 2);
+}
+
+#[test]
+fn structure_without_partial_eq() {
+    let root: Node<Box<dyn Fn() -> i32>> = Node::new(Box::new(|| 1));
+    let child: Node<Box<dyn Fn() -> i32>> = Node::new(Box::new(|| 2));
+    assert!(root.add_child_unchecked(&child));
+    assert!(child.add_child_unchecked(&Node::new(Box::new(|| 3))));
+
+    let sum: i32 = root.iter_dfs().map(|node| (node.borrow_value())()).sum();
+    assert_eq!(sum, 6);
+    assert_eq!(root.len(), 3);
+    assert!(child.parent().unwrap() == root);
+    assert!(child.detach() == child);
+    assert_eq!(root.len(), 1);
+}